@@ -76,6 +76,10 @@ async function fetchAndVerifyVerifications(pagination: Pagination): Promise<GetV
               accountId: account.nearAccountId,
             })
           }
+        } else if (account.contextStorage === "hash_only") {
+          signatureError = "Signature data is kept off-chain (hash-only record)"
+        } else if (account.contextStorage === "pruned") {
+          signatureError = "Signature data was deleted by the account holder"
         } else {
           signatureError = "Could not parse signature data from userContextData"
         }
@@ -16,6 +16,7 @@ import { KeyPairSigner } from "@near-js/signers"
 import {
   contractVerificationSchema,
   contractVerificationSummarySchema,
+  type ContractPage,
   type ContractVerification,
   type ContractVerificationSummary,
   type ContractSignatureInput,
@@ -231,7 +232,7 @@ export class NearContractDatabase implements IVerificationDatabase {
             signature_data: nearSigData,
             user_context_data: userContextData,
           },
-          gas: "30000000000000", // 30 TGas
+          gas: "100000000000000", // 100 TGas (covers consumer notifications)
           deposit: "1", // 1 yoctoNEAR deposit (required by assert_one_yocto)
           waitUntil: "EXECUTED_OPTIMISTIC",
         }),
@@ -312,22 +313,23 @@ export class NearContractDatabase implements IVerificationDatabase {
     await this.ensureInitialized()
 
     try {
-      const [total, accounts] = await Promise.all([
-        this.provider!.callFunction<number>(this.contractId, "get_verified_count", {}),
-        this.provider!.callFunction<ContractVerification[]>(this.contractId, "list_verifications", {
+      const page = await this.provider!.callFunction<ContractPage<ContractVerification>>(
+        this.contractId,
+        "list_verifications",
+        {
           from_index: fromIndex,
           limit: Math.min(limit, 100),
-        }),
-      ])
+        },
+      )
 
       // Validate and transform each contract response using Zod schema
       // Use safeParse to filter out invalid entries instead of failing the entire list
-      const verifications = (accounts ?? [])
+      const verifications = (page?.items ?? [])
         .map((item) => contractVerificationSchema.safeParse(item))
         .filter((r): r is { success: true; data: TransformedVerification } => r.success)
         .map((r) => r.data)
 
-      return { accounts: verifications, total: total ?? 0 }
+      return { accounts: verifications, total: page?.total ?? 0 }
     } catch {
       return { accounts: [], total: 0 }
     }
@@ -356,13 +358,17 @@ export class NearContractDatabase implements IVerificationDatabase {
     const limit = Math.min(pageSize, remaining, 100)
     const fromIndex = Math.max(total - (safePage + 1) * pageSize, 0)
 
-    const accounts = await this.provider!.callFunction<ContractVerification[]>(this.contractId, "list_verifications", {
-      from_index: fromIndex,
-      limit,
-    })
+    const page = await this.provider!.callFunction<ContractPage<ContractVerification>>(
+      this.contractId,
+      "list_verifications",
+      {
+        from_index: fromIndex,
+        limit,
+      },
+    )
 
     // Use safeParse to filter out invalid entries instead of failing the entire list
-    const verifications = (accounts ?? [])
+    const verifications = (page?.items ?? [])
       .map((item) => contractVerificationSchema.safeParse(item))
       .filter((r): r is { success: true; data: TransformedVerification } => r.success)
       .map((r) => r.data)
@@ -344,11 +344,30 @@ export function createVerificationError(
   }
 }
 
+/**
+ * Contract error codes (see `get_error_catalog` on the verification contract) that map to
+ * user-facing verification errors. Unlisted codes fall through to message matching.
+ */
+const CONTRACT_ERROR_CODES = {
+  ERR_ALREADY_VERIFIED: "ACCOUNT_ALREADY_VERIFIED",
+  ERR_PAUSED: "CONTRACT_PAUSED",
+  ERR_INVALID_SIGNATURE: "NEAR_SIGNATURE_INVALID",
+  ERR_SIGNATURE_ACCOUNT_MISMATCH: "NEAR_SIGNATURE_INVALID",
+  ERR_SIGNATURE_RECIPIENT_MISMATCH: "NEAR_SIGNATURE_INVALID",
+} as const satisfies Record<string, VerificationErrorCode>
+
 /**
  * Map contract/storage error messages to error codes.
  * Returns null for unknown errors to allow webhook retries.
  */
 export function mapContractErrorToCode(errorMessage: string): VerificationErrorCode | null {
+  // Prefer stable contract error codes ("ERR_XXX: message"); English text may change between releases
+  const contractCode = errorMessage.match(/\bERR_[A-Z_]+/)?.[0]
+  if (contractCode && contractCode in CONTRACT_ERROR_CODES) {
+    return CONTRACT_ERROR_CODES[contractCode as keyof typeof CONTRACT_ERROR_CODES]
+  }
+
+  // Fallback for deployments that predate error codes
   const message = errorMessage.toLowerCase()
 
   if (message.includes("sumsub applicant already used") || message.includes("already registered")) {
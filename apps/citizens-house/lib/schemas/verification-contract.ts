@@ -30,6 +30,12 @@ export interface ContractSignatureInput {
   challenge: string
   nonce: string
   recipient: string
+  /** App the signature was collected for; the wallet signs "[app_id] challenge" when set */
+  app_id?: string
+  /** SHA-256 hex of the accepted terms version; must also appear in the signed challenge */
+  terms_hash?: string
+  /** Verified citizen credited with the referral; not part of the signed message */
+  referred_by?: string
 }
 
 /**
@@ -42,16 +48,31 @@ export const contractVerificationSchema = z
     near_account_id: nearAccountIdSchema,
     verified_at: z.number(),
     user_context_data: z.string().max(SIZE_LIMITS.USER_CONTEXT_DATA),
+    // Record version 2+: hex SHA-256 of the submitted context data and how it is stored
+    user_context_hash: z.string().optional(),
+    context_storage: z.enum(["full", "hash_only", "pruned"]).default("full"),
   })
   .transform((data) => ({
     nearAccountId: data.near_account_id,
     verifiedAt: Math.floor(data.verified_at / 1_000_000),
     userContextData: data.user_context_data,
+    userContextHash: data.user_context_hash,
+    contextStorage: data.context_storage,
   }))
 
 export type ContractVerification = z.input<typeof contractVerificationSchema>
 export type TransformedVerification = z.output<typeof contractVerificationSchema>
 
+/**
+ * Contract output for paginated listings (`list_verifications`, `list_verifications_before`).
+ */
+export interface ContractPage<T> {
+  items: T[]
+  total: number
+  /** `from_index` of the next page; null on the last page */
+  next_index: number | null
+}
+
 /**
  * Contract output schema for verification summaries (without userContextData).
  * Transforms from snake_case contract output to camelCase app format.
@@ -0,0 +1,264 @@
+//! # Mock Verified Accounts Contract
+//!
+//! Test double for `verified-accounts`, for near-workspaces tests of consumer contracts
+//! (governance, bridges). Serves the full `VerifiedAccountsInterface` read surface, but
+//! verification state is set directly with `mock_*` methods instead of through signed
+//! backend submissions.
+//!
+//! **Never deploy this contract outside of test networks**: anyone can change its state.
+//!
+//! ## Interface
+//! `interface.rs` is compiled from the `verified-accounts` source tree, so record and
+//! response types always match the real contract.
+
+use near_sdk::store::{IterableMap, LookupMap, Vector};
+use near_sdk::{env, near, AccountId, BorshStorageKey, PanicOnDefault, PublicKey};
+
+#[path = "../../verified-accounts/src/interface.rs"]
+pub mod interface;
+pub use interface::{
+    ext_verified_accounts, ContextStorage, IndexedEvent, MembershipChange, MembershipDiff, Page,
+    Verification, VerificationStatistics, VerificationSummary, VerifiedAccountsInterface,
+    VersionedVerification, CURRENT_VERIFICATION_VERSION, INTERFACE_VERSION,
+};
+
+/// Maximum accounts per batch query (matches verified-accounts)
+const MAX_BATCH_SIZE: usize = 100;
+
+/// Maximum records per page (matches verified-accounts)
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// State version reported by `get_statistics` (matches verified-accounts)
+const MOCK_STATE_VERSION: u8 = 2;
+
+/// Error code prefixed to oversized batch panics (matches verified-accounts)
+const ERR_BATCH_TOO_LARGE: &str = "ERR_BATCH_TOO_LARGE";
+
+#[derive(BorshStorageKey)]
+#[near]
+pub enum StorageKey {
+    Accounts,
+    MembershipLog,
+    LinkedKeys,
+}
+
+/// Stored membership log entry
+#[near(serializers = [borsh])]
+#[derive(Clone, Debug)]
+pub struct MembershipEntry {
+    pub account_id: AccountId,
+    pub change: MembershipChange,
+    pub timestamp: u64,
+}
+
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct MockVerifiedAccounts {
+    verifications: IterableMap<AccountId, VersionedVerification>,
+    membership_log: Vector<MembershipEntry>,
+    linked_keys: LookupMap<AccountId, PublicKey>,
+    paused: bool,
+}
+
+#[near]
+impl MockVerifiedAccounts {
+    #[init]
+    pub fn new() -> Self {
+        Self {
+            verifications: IterableMap::new(StorageKey::Accounts),
+            membership_log: Vector::new(StorageKey::MembershipLog),
+            linked_keys: LookupMap::new(StorageKey::LinkedKeys),
+            paused: false,
+        }
+    }
+
+    // ==================== Mock Controls ====================
+
+    /// Mark an account as verified at `verified_at` (nanoseconds).
+    ///
+    /// Overwrites the timestamp of an existing record. Newly verified accounts are
+    /// appended to the membership log; records carry empty context data.
+    pub fn mock_set_verified(&mut self, account_id: AccountId, verified_at: u64) {
+        let is_new = !self.verifications.contains_key(&account_id);
+        let verification = Verification {
+            near_account_id: account_id.clone(),
+            verified_at,
+            user_context_data: String::new(),
+            user_context_hash: Verification::hash_context(""),
+            context_storage: ContextStorage::Full,
+        };
+        self.verifications.insert(
+            account_id.clone(),
+            VersionedVerification::from(verification),
+        );
+        if is_new {
+            self.membership_log.push(MembershipEntry {
+                account_id,
+                change: MembershipChange::Added,
+                timestamp: verified_at,
+            });
+        }
+    }
+
+    /// Remove an account's verification record and linked key.
+    ///
+    /// The membership log is left untouched: the interface has no removal change yet.
+    pub fn mock_clear_verified(&mut self, account_id: AccountId) {
+        self.verifications.remove(&account_id);
+        self.linked_keys.remove(&account_id);
+    }
+
+    /// Set or clear the key returned by `get_linked_key`
+    pub fn mock_set_linked_key(&mut self, account_id: AccountId, public_key: Option<PublicKey>) {
+        match public_key {
+            Some(public_key) => self.linked_keys.insert(account_id, public_key),
+            None => self.linked_keys.remove(&account_id),
+        };
+    }
+
+    /// Set the value returned by `is_paused` and `get_statistics`
+    pub fn mock_set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+}
+
+#[near]
+impl VerifiedAccountsInterface for MockVerifiedAccounts {
+    fn is_verified(&self, account_id: AccountId) -> bool {
+        self.verifications.contains_key(&account_id)
+    }
+
+    fn is_account_verified_since(
+        &self,
+        account_id: AccountId,
+        min_verified_duration_ns: u64,
+    ) -> bool {
+        self.verifications.get(&account_id).is_some_and(|v| {
+            env::block_timestamp().saturating_sub(v.verified_at()) >= min_verified_duration_ns
+        })
+    }
+
+    fn get_verification(&self, account_id: AccountId) -> Option<VerificationSummary> {
+        self.verifications
+            .get(&account_id)
+            .map(VerificationSummary::from)
+    }
+
+    fn get_full_verification(&self, account_id: AccountId) -> Option<Verification> {
+        self.verifications
+            .get(&account_id)
+            .map(VersionedVerification::as_current)
+    }
+
+    fn get_linked_key(&self, account_id: AccountId) -> Option<PublicKey> {
+        self.linked_keys.get(&account_id).cloned()
+    }
+
+    fn are_verified(&self, account_ids: Vec<AccountId>) -> Vec<bool> {
+        require_batch_size(account_ids.len());
+        account_ids
+            .iter()
+            .map(|id| self.verifications.contains_key(id))
+            .collect()
+    }
+
+    fn get_verifications(&self, account_ids: Vec<AccountId>) -> Vec<Option<VerificationSummary>> {
+        require_batch_size(account_ids.len());
+        account_ids
+            .iter()
+            .map(|id| self.verifications.get(id).map(VerificationSummary::from))
+            .collect()
+    }
+
+    fn list_verifications(&self, from_index: u32, limit: u32) -> Page<Verification> {
+        let limit = std::cmp::min(limit, MAX_PAGE_SIZE);
+        let items = self
+            .verifications
+            .values()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .map(VersionedVerification::as_current)
+            .collect();
+        Page::new(items, from_index, limit, self.verifications.len())
+    }
+
+    fn list_verifications_before(
+        &self,
+        timestamp: u64,
+        from_index: u32,
+        limit: u32,
+    ) -> Page<VerificationSummary> {
+        let limit = std::cmp::min(limit, MAX_PAGE_SIZE);
+        let items = self
+            .verifications
+            .values()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .map(VerificationSummary::from)
+            .filter(|summary| summary.verified_at < timestamp)
+            .collect();
+        Page::new(items, from_index, limit, self.verifications.len())
+    }
+
+    fn get_verified_count(&self) -> u32 {
+        self.verifications.len()
+    }
+
+    fn get_statistics(&self) -> VerificationStatistics {
+        // Mock timestamps are arbitrary, so insertion order says nothing about age
+        let timestamps: Vec<u64> = self
+            .verifications
+            .values()
+            .map(VersionedVerification::verified_at)
+            .collect();
+        VerificationStatistics {
+            verified_count: self.verifications.len(),
+            first_verified_at: timestamps.iter().min().copied(),
+            last_verified_at: timestamps.iter().max().copied(),
+            paused: self.paused,
+            state_version: MOCK_STATE_VERSION,
+            record_version: CURRENT_VERIFICATION_VERSION,
+        }
+    }
+
+    fn interface_version(&self) -> String {
+        INTERFACE_VERSION.to_string()
+    }
+
+    fn get_membership_seq(&self) -> u64 {
+        u64::from(self.membership_log.len())
+    }
+
+    fn get_membership_diffs(&self, from_seq: u64, limit: u32) -> Vec<MembershipDiff> {
+        self.membership_log
+            .iter()
+            .zip(0u64..)
+            .skip(from_seq as usize)
+            .take(std::cmp::min(limit, MAX_PAGE_SIZE) as usize)
+            .map(|(entry, seq)| MembershipDiff {
+                seq,
+                account_id: entry.account_id.clone(),
+                change: entry.change.clone(),
+                timestamp: entry.timestamp,
+            })
+            .collect()
+    }
+
+    /// The mock emits no indexed events.
+    fn get_events(&self, _from_seq: u64, _limit: u32) -> Vec<IndexedEvent> {
+        Vec::new()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+fn require_batch_size(len: usize) {
+    if len > MAX_BATCH_SIZE {
+        env::panic_str(&format!(
+            "{}: Batch size exceeds maximum of {} accounts",
+            ERR_BATCH_TOO_LARGE, MAX_BATCH_SIZE
+        ));
+    }
+}
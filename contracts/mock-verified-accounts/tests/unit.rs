@@ -0,0 +1,162 @@
+//! Unit tests for mock-verified-accounts contract
+//!
+//! Run with: cargo test --test unit
+
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::indexing_slicing
+)]
+
+use allure_rs::prelude::*;
+use mock_verified_accounts::{
+    MembershipChange, MockVerifiedAccounts, VerifiedAccountsInterface, INTERFACE_VERSION,
+};
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, PublicKey};
+
+fn setup() -> MockVerifiedAccounts {
+    let mut builder = VMContextBuilder::new();
+    builder.current_account_id(accounts(0));
+    builder.predecessor_account_id(accounts(1));
+    testing_env!(builder.build());
+    MockVerifiedAccounts::new()
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Mock Verified Accounts Unit Tests")]
+#[allure_sub_suite("Mock Controls")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "mock")]
+#[allure_description(
+    "Verifies mock_set_verified, mock_set_linked_key and mock_clear_verified are reflected by the interface views."
+)]
+#[allure_test]
+#[test]
+fn test_set_and_clear_verified() {
+    let mut contract = step("Initialize mock", setup);
+
+    step("Set two accounts verified", || {
+        contract.mock_set_verified(accounts(2), 2_000);
+        contract.mock_set_verified(accounts(3), 1_000);
+    });
+
+    step("Verify interface views reflect mock state", || {
+        assert!(contract.is_verified(accounts(2)));
+        assert_eq!(
+            contract.are_verified(vec![accounts(2), accounts(3), accounts(4)]),
+            vec![true, true, false]
+        );
+        let summary = contract.get_verification(accounts(2)).unwrap();
+        assert_eq!(summary.verified_at, 2_000);
+        assert_eq!(contract.get_verified_count(), 2);
+        assert_eq!(
+            contract.list_verifications_before(1_500, 0, 10).items.len(),
+            1
+        );
+
+        let stats = contract.get_statistics();
+        assert_eq!(stats.first_verified_at, Some(1_000));
+        assert_eq!(stats.last_verified_at, Some(2_000));
+        assert_eq!(contract.interface_version(), INTERFACE_VERSION);
+    });
+
+    step("Overwrite timestamp of an existing account", || {
+        contract.mock_set_verified(accounts(2), 3_000);
+        let full = contract.get_full_verification(accounts(2)).unwrap();
+        assert_eq!(full.verified_at, 3_000);
+        assert_eq!(contract.get_verified_count(), 2);
+    });
+
+    step("Link a key", || {
+        let public_key: PublicKey = "ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847"
+            .parse()
+            .unwrap();
+        contract.mock_set_linked_key(accounts(2), Some(public_key.clone()));
+        assert_eq!(contract.get_linked_key(accounts(2)), Some(public_key));
+        assert_eq!(contract.get_linked_key(accounts(3)), None);
+    });
+
+    step("Clear a verification", || {
+        contract.mock_clear_verified(accounts(2));
+        assert!(!contract.is_verified(accounts(2)));
+        assert_eq!(contract.get_verified_count(), 1);
+        assert_eq!(contract.get_linked_key(accounts(2)), None);
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Mock Verified Accounts Unit Tests")]
+#[allure_sub_suite("Mock Controls")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "mock", "membership")]
+#[allure_description(
+    "Verifies only newly verified accounts are appended to the membership log, and pause state is settable."
+)]
+#[allure_test]
+#[test]
+fn test_membership_log_and_pause() {
+    let mut contract = step("Initialize mock", setup);
+
+    step("Verify, re-verify and verify another account", || {
+        contract.mock_set_verified(accounts(2), 1_000);
+        contract.mock_set_verified(accounts(2), 2_000);
+        contract.mock_set_verified(accounts(3), 3_000);
+    });
+
+    step(
+        "Verify membership log has one entry per new account",
+        || {
+            assert_eq!(contract.get_membership_seq(), 2);
+            let diffs = contract.get_membership_diffs(1, 10);
+            assert_eq!(diffs.len(), 1);
+            assert_eq!(diffs[0].seq, 1);
+            assert_eq!(diffs[0].account_id, accounts(3));
+            assert_eq!(diffs[0].change, MembershipChange::Added);
+        },
+    );
+
+    step("Toggle pause state", || {
+        assert!(!contract.is_paused());
+        contract.mock_set_paused(true);
+        assert!(contract.is_paused());
+        assert!(contract.get_statistics().paused);
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Mock Verified Accounts Unit Tests")]
+#[allure_sub_suite("Interface Parity")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "mock", "errors")]
+#[allure_description(
+    "Verifies the mock reports the real contract's state version and rejects oversized batches with the same coded panic."
+)]
+#[allure_test]
+#[test]
+fn test_matches_real_contract_versions_and_errors() {
+    let contract = step("Initialize mock", setup);
+
+    step("Report state version 2", || {
+        assert_eq!(contract.get_statistics().state_version, 2);
+    });
+
+    step("Reject an oversized batch with ERR_BATCH_TOO_LARGE", || {
+        let account_ids = vec![accounts(2); 101];
+        let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            contract.are_verified(account_ids)
+        }))
+        .unwrap_err();
+        let msg = err
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| err.downcast_ref::<&str>().map(|s| s.to_string()))
+            .unwrap();
+        assert!(
+            msg.contains("ERR_BATCH_TOO_LARGE: Batch size exceeds maximum of 100 accounts"),
+            "unexpected panic: {}",
+            msg
+        );
+    });
+}
@@ -0,0 +1,55 @@
+//! # Citizen Weight
+//!
+//! Vote weight for SputnikDAO `TokenWeight` policies. A SputnikDAO v2 DAO does not read
+//! token balances; it keeps per-account delegations that only its staking contract can
+//! change, through `register_delegation` and `delegate`. This contract acts as that
+//! staking contract: once a DAO names it in a `SetStakingContract` proposal and the
+//! backend registers the DAO with `set_weight_dao`, every verified account delegates
+//! exactly 1 unit to itself in that DAO.
+//!
+//! Verifications are never removed, so weight is only ever added. `ft_total_supply` and
+//! `ft_balance_of` mirror the views of the reference `sputnik-staking` contract; this is
+//! not a NEP-141 token and there is no transfer, storage or metadata surface.
+
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, Gas, NearToken, Promise};
+
+/// Storage bytes a DAO charges its staking contract for `register_delegation`
+pub const DELEGATION_REGISTRATION_BYTES: u128 = 16;
+
+/// Gas attached to each of `register_delegation` and `delegate`
+pub const DELEGATION_GAS: Gas = Gas::from_tgas(10);
+
+/// Gas attached to the `on_weight_delegated` callback that records a delegation
+pub const DELEGATION_CALLBACK_GAS: Gas = Gas::from_tgas(5);
+
+/// Weight held by an account: 1 if verified, 0 otherwise
+pub fn weight_of(verified: bool) -> U128 {
+    U128(u128::from(verified))
+}
+
+/// Register `account_id` with `dao` and delegate 1 unit to it.
+///
+/// Both calls go in one batch receipt, so a failed `delegate` also rolls back the
+/// registration. The caller chains a callback on the returned promise to learn the outcome.
+pub fn delegate_weight(dao: AccountId, account_id: &AccountId) -> Promise {
+    let registration_deposit =
+        env::storage_byte_cost().saturating_mul(DELEGATION_REGISTRATION_BYTES);
+    Promise::new(dao)
+        .function_call(
+            "register_delegation".to_string(),
+            near_sdk::serde_json::json!({ "account_id": account_id })
+                .to_string()
+                .into_bytes(),
+            registration_deposit,
+            DELEGATION_GAS,
+        )
+        .function_call(
+            "delegate".to_string(),
+            near_sdk::serde_json::json!({ "account_id": account_id, "amount": weight_of(true) })
+                .to_string()
+                .into_bytes(),
+            NearToken::from_yoctonear(0),
+            DELEGATION_GAS,
+        )
+}
@@ -0,0 +1,303 @@
+//! # Error Codes
+//!
+//! Stable, machine-readable error codes for every panic raised by this contract.
+//!
+//! Panic messages are formatted as `"<CODE>: <human readable message>"`. Clients should
+//! branch on the code prefix; the English text is informational and may change between
+//! releases. `get_error_catalog()` returns the full list at runtime.
+//!
+//! Codes are part of the public API: never rename or reuse one, only append.
+
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, NearSchema};
+
+/// Machine-readable error code prefixed to every contract panic.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ErrorCode {
+    #[serde(rename = "ERR_UNAUTHORIZED")]
+    Unauthorized,
+    #[serde(rename = "ERR_DEPOSIT_REQUIRED")]
+    DepositRequired,
+    #[serde(rename = "ERR_PAUSED")]
+    Paused,
+    #[serde(rename = "ERR_ALREADY_PAUSED")]
+    AlreadyPaused,
+    #[serde(rename = "ERR_NOT_PAUSED")]
+    NotPaused,
+    #[serde(rename = "ERR_ALREADY_VERIFIED")]
+    AlreadyVerified,
+    #[serde(rename = "ERR_INPUT_TOO_LONG")]
+    InputTooLong,
+    #[serde(rename = "ERR_BATCH_TOO_LARGE")]
+    BatchTooLarge,
+    #[serde(rename = "ERR_SIGNATURE_ACCOUNT_MISMATCH")]
+    SignatureAccountMismatch,
+    #[serde(rename = "ERR_SIGNATURE_RECIPIENT_MISMATCH")]
+    SignatureRecipientMismatch,
+    #[serde(rename = "ERR_INVALID_NONCE")]
+    InvalidNonce,
+    #[serde(rename = "ERR_INVALID_SIGNATURE_FORMAT")]
+    InvalidSignatureFormat,
+    #[serde(rename = "ERR_UNSUPPORTED_PUBLIC_KEY")]
+    UnsupportedPublicKey,
+    #[serde(rename = "ERR_INVALID_SIGNATURE")]
+    InvalidSignature,
+    #[serde(rename = "ERR_PAYLOAD_SERIALIZATION")]
+    PayloadSerialization,
+    #[serde(rename = "ERR_CONSUMER_LIMIT")]
+    ConsumerLimit,
+    #[serde(rename = "ERR_CONSUMER_ALREADY_REGISTERED")]
+    ConsumerAlreadyRegistered,
+    #[serde(rename = "ERR_CONSUMER_NOT_REGISTERED")]
+    ConsumerNotRegistered,
+    #[serde(rename = "ERR_NO_STATE")]
+    NoState,
+    #[serde(rename = "ERR_ROTATION_IN_PROGRESS")]
+    RotationInProgress,
+    #[serde(rename = "ERR_NO_ROTATION")]
+    NoRotation,
+    #[serde(rename = "ERR_EXPORT_SERIALIZATION")]
+    ExportSerialization,
+    #[serde(rename = "ERR_APP_ID_NOT_ALLOWED")]
+    AppIdNotAllowed,
+    #[serde(rename = "ERR_APP_ID_ALREADY_ALLOWED")]
+    AppIdAlreadyAllowed,
+    #[serde(rename = "ERR_INVALID_PERMISSION")]
+    InvalidPermission,
+    #[serde(rename = "ERR_PERMISSION_NOT_FOUND")]
+    PermissionNotFound,
+    #[serde(rename = "ERR_ALREADY_REQUESTED")]
+    AlreadyRequested,
+    #[serde(rename = "ERR_QUEUE_FULL")]
+    QueueFull,
+    #[serde(rename = "ERR_REQUEST_NOT_FOUND")]
+    RequestNotFound,
+    #[serde(rename = "ERR_NOT_VERIFIED")]
+    NotVerified,
+    #[serde(rename = "ERR_NOTHING_TO_PRUNE")]
+    NothingToPrune,
+    #[serde(rename = "ERR_INVALID_TERMS_HASH")]
+    InvalidTermsHash,
+    #[serde(rename = "ERR_TERMS_ALREADY_REGISTERED")]
+    TermsAlreadyRegistered,
+    #[serde(rename = "ERR_TERMS_NOT_ACCEPTED")]
+    TermsNotAccepted,
+    #[serde(rename = "ERR_MPC_NOT_CONFIGURED")]
+    MpcNotConfigured,
+    #[serde(rename = "ERR_INVALID_MPC_KEY")]
+    InvalidMpcKey,
+    #[serde(rename = "ERR_INVALID_MPC_SIGNATURE")]
+    InvalidMpcSignature,
+    #[serde(rename = "ERR_STALE_MPC_NONCE")]
+    StaleMpcNonce,
+    #[serde(rename = "ERR_INVALID_PROFILE")]
+    InvalidProfile,
+    #[serde(rename = "ERR_INVALID_REFERRER")]
+    InvalidReferrer,
+    #[serde(rename = "ERR_REFERRAL_LIMIT")]
+    ReferralLimit,
+    #[serde(rename = "ERR_INVALID_CHALLENGE")]
+    InvalidChallenge,
+    #[serde(rename = "ERR_NONCE_REUSED")]
+    NonceReused,
+    #[serde(rename = "ERR_WEIGHT_DAO_NOT_CONFIGURED")]
+    WeightDaoNotConfigured,
+    #[serde(rename = "ERR_REQUEST_DEPOSIT_REQUIRED")]
+    RequestDepositRequired,
+}
+
+impl ErrorCode {
+    /// Every error code, in catalog order.
+    pub const ALL: [ErrorCode; 45] = [
+        Self::Unauthorized,
+        Self::DepositRequired,
+        Self::Paused,
+        Self::AlreadyPaused,
+        Self::NotPaused,
+        Self::AlreadyVerified,
+        Self::InputTooLong,
+        Self::BatchTooLarge,
+        Self::SignatureAccountMismatch,
+        Self::SignatureRecipientMismatch,
+        Self::InvalidNonce,
+        Self::InvalidSignatureFormat,
+        Self::UnsupportedPublicKey,
+        Self::InvalidSignature,
+        Self::PayloadSerialization,
+        Self::ConsumerLimit,
+        Self::ConsumerAlreadyRegistered,
+        Self::ConsumerNotRegistered,
+        Self::NoState,
+        Self::RotationInProgress,
+        Self::NoRotation,
+        Self::ExportSerialization,
+        Self::AppIdNotAllowed,
+        Self::AppIdAlreadyAllowed,
+        Self::InvalidPermission,
+        Self::PermissionNotFound,
+        Self::AlreadyRequested,
+        Self::QueueFull,
+        Self::RequestNotFound,
+        Self::NotVerified,
+        Self::NothingToPrune,
+        Self::InvalidTermsHash,
+        Self::TermsAlreadyRegistered,
+        Self::TermsNotAccepted,
+        Self::MpcNotConfigured,
+        Self::InvalidMpcKey,
+        Self::InvalidMpcSignature,
+        Self::StaleMpcNonce,
+        Self::InvalidProfile,
+        Self::InvalidReferrer,
+        Self::ReferralLimit,
+        Self::InvalidChallenge,
+        Self::NonceReused,
+        Self::WeightDaoNotConfigured,
+        Self::RequestDepositRequired,
+    ];
+
+    /// String-stable code (matches the serde representation).
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unauthorized => "ERR_UNAUTHORIZED",
+            Self::DepositRequired => "ERR_DEPOSIT_REQUIRED",
+            Self::Paused => "ERR_PAUSED",
+            Self::AlreadyPaused => "ERR_ALREADY_PAUSED",
+            Self::NotPaused => "ERR_NOT_PAUSED",
+            Self::AlreadyVerified => "ERR_ALREADY_VERIFIED",
+            Self::InputTooLong => "ERR_INPUT_TOO_LONG",
+            Self::BatchTooLarge => "ERR_BATCH_TOO_LARGE",
+            Self::SignatureAccountMismatch => "ERR_SIGNATURE_ACCOUNT_MISMATCH",
+            Self::SignatureRecipientMismatch => "ERR_SIGNATURE_RECIPIENT_MISMATCH",
+            Self::InvalidNonce => "ERR_INVALID_NONCE",
+            Self::InvalidSignatureFormat => "ERR_INVALID_SIGNATURE_FORMAT",
+            Self::UnsupportedPublicKey => "ERR_UNSUPPORTED_PUBLIC_KEY",
+            Self::InvalidSignature => "ERR_INVALID_SIGNATURE",
+            Self::PayloadSerialization => "ERR_PAYLOAD_SERIALIZATION",
+            Self::ConsumerLimit => "ERR_CONSUMER_LIMIT",
+            Self::ConsumerAlreadyRegistered => "ERR_CONSUMER_ALREADY_REGISTERED",
+            Self::ConsumerNotRegistered => "ERR_CONSUMER_NOT_REGISTERED",
+            Self::NoState => "ERR_NO_STATE",
+            Self::RotationInProgress => "ERR_ROTATION_IN_PROGRESS",
+            Self::NoRotation => "ERR_NO_ROTATION",
+            Self::ExportSerialization => "ERR_EXPORT_SERIALIZATION",
+            Self::AppIdNotAllowed => "ERR_APP_ID_NOT_ALLOWED",
+            Self::AppIdAlreadyAllowed => "ERR_APP_ID_ALREADY_ALLOWED",
+            Self::InvalidPermission => "ERR_INVALID_PERMISSION",
+            Self::PermissionNotFound => "ERR_PERMISSION_NOT_FOUND",
+            Self::AlreadyRequested => "ERR_ALREADY_REQUESTED",
+            Self::QueueFull => "ERR_QUEUE_FULL",
+            Self::RequestNotFound => "ERR_REQUEST_NOT_FOUND",
+            Self::NotVerified => "ERR_NOT_VERIFIED",
+            Self::NothingToPrune => "ERR_NOTHING_TO_PRUNE",
+            Self::InvalidTermsHash => "ERR_INVALID_TERMS_HASH",
+            Self::TermsAlreadyRegistered => "ERR_TERMS_ALREADY_REGISTERED",
+            Self::TermsNotAccepted => "ERR_TERMS_NOT_ACCEPTED",
+            Self::MpcNotConfigured => "ERR_MPC_NOT_CONFIGURED",
+            Self::InvalidMpcKey => "ERR_INVALID_MPC_KEY",
+            Self::InvalidMpcSignature => "ERR_INVALID_MPC_SIGNATURE",
+            Self::StaleMpcNonce => "ERR_STALE_MPC_NONCE",
+            Self::InvalidProfile => "ERR_INVALID_PROFILE",
+            Self::InvalidReferrer => "ERR_INVALID_REFERRER",
+            Self::ReferralLimit => "ERR_REFERRAL_LIMIT",
+            Self::InvalidChallenge => "ERR_INVALID_CHALLENGE",
+            Self::NonceReused => "ERR_NONCE_REUSED",
+            Self::WeightDaoNotConfigured => "ERR_WEIGHT_DAO_NOT_CONFIGURED",
+            Self::RequestDepositRequired => "ERR_REQUEST_DEPOSIT_REQUIRED",
+        }
+    }
+
+    /// Short description for the error catalog.
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Unauthorized => "Caller is not allowed to perform this action",
+            Self::DepositRequired => "Exactly 1 yoctoNEAR must be attached",
+            Self::Paused => "Contract is paused; writes are disabled",
+            Self::AlreadyPaused => "Contract is already paused",
+            Self::NotPaused => "Contract is not paused",
+            Self::AlreadyVerified => "NEAR account is already verified",
+            Self::InputTooLong => "An input exceeds its maximum length",
+            Self::BatchTooLarge => "Batch exceeds the maximum number of accounts",
+            Self::SignatureAccountMismatch => "Signed account does not match the target account",
+            Self::SignatureRecipientMismatch => "Signed recipient is not allowed",
+            Self::InvalidNonce => "NEP-413 nonce must be exactly 32 bytes",
+            Self::InvalidSignatureFormat => "Signature must be 64 bytes",
+            Self::UnsupportedPublicKey => "Only 32-byte ED25519 public keys are supported",
+            Self::InvalidSignature => "NEP-413 signature verification failed",
+            Self::PayloadSerialization => "NEP-413 payload could not be serialized",
+            Self::ConsumerLimit => "Maximum number of consumers reached",
+            Self::ConsumerAlreadyRegistered => "Consumer is already registered",
+            Self::ConsumerNotRegistered => "Consumer is not registered",
+            Self::NoState => "No contract state to migrate",
+            Self::RotationInProgress => "A backend wallet rotation is already in progress",
+            Self::NoRotation => "No backend wallet rotation is in progress",
+            Self::ExportSerialization => "State chunk could not be serialized",
+            Self::AppIdNotAllowed => "Signature app ID is missing or not in the allowlist",
+            Self::AppIdAlreadyAllowed => "App ID is already in the allowlist",
+            Self::InvalidPermission => "Grant must name a method and expire in the future",
+            Self::PermissionNotFound => "Account has no permission grant",
+            Self::AlreadyRequested => "Account already has a queued verification request",
+            Self::QueueFull => "Verification request queue is full",
+            Self::RequestNotFound => "Account has no queued verification request",
+            Self::NotVerified => "NEAR account is not verified",
+            Self::NothingToPrune => "Record holds no context data to prune",
+            Self::InvalidTermsHash => "Terms hash must be 64 lowercase hex characters",
+            Self::TermsAlreadyRegistered => "Terms version is already registered",
+            Self::TermsNotAccepted => "Signature does not reference a registered terms version",
+            Self::MpcNotConfigured => "No MPC public key is configured",
+            Self::InvalidMpcKey => "MPC public key must be 64 bytes (uncompressed secp256k1)",
+            Self::InvalidMpcSignature => "MPC signature does not match the configured key",
+            Self::StaleMpcNonce => "MPC nonce must be greater than the last one used",
+            Self::InvalidProfile => "Profile handle hash is malformed or has too many links",
+            Self::InvalidReferrer => "Referrer is not a verified account",
+            Self::ReferralLimit => "Referrer has reached the maximum number of referrals",
+            Self::InvalidChallenge => "Signed challenge is not the one this method requires",
+            Self::NonceReused => "NEP-413 nonce has already been used",
+            Self::WeightDaoNotConfigured => "No weight DAO is configured",
+            Self::RequestDepositRequired => {
+                "The verification request deposit must be attached exactly"
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Entry returned by `get_error_catalog()`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ErrorCatalogEntry {
+    pub code: ErrorCode,
+    pub description: String,
+}
+
+/// Abort execution with a `"<CODE>: <message>"` panic.
+pub fn panic_with_code(code: ErrorCode, message: &str) -> ! {
+    env::panic_str(&format!("{}: {}", code, message))
+}
+
+/// Abort with a coded panic unless `cond` holds.
+///
+/// The message is only formatted on failure, so this is as cheap as `require!`
+/// on the happy path.
+macro_rules! ensure {
+    ($cond:expr, $code:expr, $($arg:tt)+) => {
+        if !$cond {
+            $crate::errors::panic_with_code($code, &format!($($arg)+))
+        }
+    };
+}
+
+/// Coded equivalent of `near_sdk::assert_one_yocto()`.
+pub fn assert_one_yocto() {
+    ensure!(
+        env::attached_deposit().as_yoctonear() == 1,
+        ErrorCode::DepositRequired,
+        "Requires attached deposit of exactly 1 yoctoNEAR"
+    );
+}
@@ -6,10 +6,13 @@
 //! - `VersionedVerification` wraps stored records; `Verification` aliases the current version.
 //! - Append enum variants only; migrate in `into_current()`. Borsh order is binding.
 //! - Old records are lazily upgraded on read.
+//! - `INTERFACE_VERSION` (semver) covers the cross-contract surface; consumers should check
+//!   it with `is_interface_compatible` before wiring against a deployment.
 
 use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::{Base58CryptoHash, U128};
 use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::{ext_contract, AccountId, NearSchema};
+use near_sdk::{env, ext_contract, AccountId, NearSchema, PublicKey};
 
 // ==================== Versioning ====================
 
@@ -20,58 +23,90 @@ use near_sdk::{ext_contract, AccountId, NearSchema};
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[borsh(crate = "near_sdk::borsh")]
 pub enum VersionedVerification {
-    /// V1: SumSub-based verification (current version)
+    /// V1: Original SumSub-based verification
     V1(VerificationV1),
+    /// V2: Adds context hash and storage mode (current version)
+    V2(VerificationV2),
     // Future versions append here:
-    // V2(VerificationV2),
+    // V3(VerificationV3),
+}
+
+/// Semver of the `VerifiedAccountsInterface` surface, returned by `interface_version()`.
+///
+/// Bump minor for additive changes (new methods, optional fields) and major for anything
+/// that breaks existing callers. 1.0.0 is the original single/batch query surface.
+pub const INTERFACE_VERSION: &str = "2.2.0";
+
+/// Whether a deployment reporting `actual` satisfies a consumer built against `required`.
+///
+/// Compatible means same major version and `actual >= required`. Malformed versions are
+/// never compatible.
+pub fn is_interface_compatible(actual: &str, required: &str) -> bool {
+    match (parse_semver(actual), parse_semver(required)) {
+        (Some(actual), Some(required)) => actual.0 == required.0 && actual >= required,
+        _ => false,
+    }
+}
+
+/// Parse `"MAJOR.MINOR.PATCH"`
+fn parse_semver(version: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = version.split('.').map(|part| part.parse::<u64>().ok());
+    let parsed = (parts.next()??, parts.next()??, parts.next()??);
+    parts.next().is_none().then_some(parsed)
 }
 
 /// Current verification version number.
 /// Update this when adding new versions.
-pub const CURRENT_VERIFICATION_VERSION: u8 = 1;
+pub const CURRENT_VERIFICATION_VERSION: u8 = 2;
 
 impl VersionedVerification {
-    /// Create a new versioned verification using the current version (V1).
+    /// Create a new versioned verification using the current version (V2).
     pub fn new(v: Verification) -> Self {
-        Self::V1(v)
+        Self::V2(v)
     }
 
     /// Convert to current Verification format.
     /// This performs lazy migration from older versions.
     pub fn into_current(self) -> Verification {
         match self {
-            Self::V1(v) => v,
-            // Future versions: migrate to current
-            // Self::V2(v) => v,
+            Self::V1(v) => VerificationV2::from(v),
+            Self::V2(v) => v,
         }
     }
 
     /// Get a reference as current Verification (cloning if migration needed).
     pub fn as_current(&self) -> Verification {
         match self {
-            Self::V1(v) => v.clone(),
-            // Future versions: migrate to current
-            // Self::V2(v) => v.clone(),
+            Self::V1(v) => VerificationV2::from(v.clone()),
+            Self::V2(v) => v.clone(),
         }
     }
 
-    /// Check if this is the current version (V1).
+    /// Check if this is the current version (V2).
     pub fn is_current(&self) -> bool {
-        matches!(self, Self::V1(_))
+        matches!(self, Self::V2(_))
     }
 
     /// Get the version number of this record.
     pub fn version(&self) -> u8 {
         match self {
             Self::V1(_) => 1,
-            // Self::V2(_) => 2,
+            Self::V2(_) => 2,
+        }
+    }
+
+    /// Verification timestamp, read without migrating (or cloning) the record.
+    pub fn verified_at(&self) -> u64 {
+        match self {
+            Self::V1(v) => v.verified_at,
+            Self::V2(v) => v.verified_at,
         }
     }
 }
 
 impl From<Verification> for VersionedVerification {
     fn from(v: Verification) -> Self {
-        Self::V1(v)
+        Self::V2(v)
     }
 }
 
@@ -95,12 +130,118 @@ pub struct VerificationSummary {
     pub verified_at: u64,
 }
 
+/// Aggregate statistics for the verified set.
+///
+/// View-only response type (not stored on-chain), so it is not versioned.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct VerificationStatistics {
+    /// Total number of verified accounts
+    pub verified_count: u32,
+    /// Timestamp (nanoseconds) of the earliest verification, if any
+    pub first_verified_at: Option<u64>,
+    /// Timestamp (nanoseconds) of the most recent verification, if any
+    pub last_verified_at: Option<u64>,
+    /// Whether new verifications are currently blocked
+    pub paused: bool,
+    /// Contract state version
+    pub state_version: u8,
+    /// Verification record version written by new verifications
+    pub record_version: u8,
+}
+
+/// One page of a paginated listing.
+///
+/// View-only response type (not stored on-chain), so it is not versioned.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Page<T> {
+    /// Entries on this page
+    pub items: Vec<T>,
+    /// Total number of entries in the listing
+    pub total: u32,
+    /// `from_index` of the next page, or `None` if this is the last page
+    pub next_index: Option<u32>,
+}
+
+impl<T> Page<T> {
+    /// Page of `items` read from `from_index` with the (already capped) `limit`
+    pub fn new(items: Vec<T>, from_index: u32, limit: u32, total: u32) -> Self {
+        let end = from_index.saturating_add(limit);
+        Self {
+            items,
+            total,
+            next_index: (end < total).then_some(end),
+        }
+    }
+}
+
+/// Kind of change recorded in the membership diff log.
+///
+/// Append new variants only (e.g. `Removed` when revocation lands).
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema,
+)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub enum MembershipChange {
+    /// Account joined the citizen set
+    Added,
+}
+
+/// Entry returned by `get_membership_diffs`.
+///
+/// Sequence numbers are gapless and start at 0, so relayers can resume from the
+/// last `seq` they applied.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MembershipDiff {
+    /// Position in the membership log
+    pub seq: u64,
+    /// Account whose membership changed
+    pub account_id: AccountId,
+    /// What changed
+    pub change: MembershipChange,
+    /// Unix timestamp (nanoseconds) of the change
+    pub timestamp: u64,
+}
+
+/// Entry returned by `get_events`.
+///
+/// Lets indexers that skipped blocks detect and recover missed events: `seq` is
+/// gapless, and `payload_hash` is the SHA-256 of the emitted `EVENT_JSON:` log line.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct IndexedEvent {
+    /// Position in the event log
+    pub seq: u64,
+    /// Event name (e.g. `verification_stored`)
+    pub kind: String,
+    /// SHA-256 of the full log line
+    pub payload_hash: Base58CryptoHash,
+    /// Block in which the event was emitted
+    pub block_height: u64,
+}
+
+/// Verification status pushed to registered consumers via `on_verification_changed`.
+///
+/// Append new variants only (e.g. when revocation lands); consumers should treat
+/// unknown statuses as "not verified".
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub enum VerificationStatus {
+    /// Account has a stored verification record
+    Verified,
+}
+
 // ==================== Versioned Verification Types ====================
 
-/// V1: SumSub-based verification format (current version).
+/// V1: Original SumSub-based verification format.
 ///
 /// Uses SumSub KYC verification. SumSub handles identity deduplication
 /// internally, so no applicant ID is stored on-chain.
+/// Do NOT modify this struct - create new versions instead.
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, NearSchema)]
 #[serde(crate = "near_sdk::serde")]
 #[borsh(crate = "near_sdk::borsh")]
@@ -113,12 +254,79 @@ pub struct VerificationV1 {
     pub user_context_data: String,
 }
 
+/// How a record holds its `user_context_data` on-chain.
+///
+/// Append new variants only; Borsh discriminants are order-based.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    NearSchema,
+)]
+#[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
+#[borsh(crate = "near_sdk::borsh")]
+pub enum ContextStorage {
+    /// Full `user_context_data` is stored
+    Full,
+    /// Only `user_context_hash` is stored; the backend keeps the data off-chain
+    HashOnly,
+    /// The account deleted its context data with `prune_my_proof_data`
+    Pruned,
+}
+
+/// V2: Adds the context hash and storage mode (current version).
+///
+/// Hash-only and pruned records keep `user_context_data` empty; `user_context_hash`
+/// always commits to the data the backend submitted.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct VerificationV2 {
+    /// The NEAR account that was verified
+    pub near_account_id: AccountId,
+    /// Unix timestamp (nanoseconds) when verification was recorded
+    pub verified_at: u64,
+    /// Additional context data from verification flow; empty unless `context_storage` is `Full`
+    pub user_context_data: String,
+    /// Lowercase hex SHA-256 of the submitted `user_context_data`
+    pub user_context_hash: String,
+    /// Whether `user_context_data` is held on-chain
+    pub context_storage: ContextStorage,
+}
+
+impl VerificationV2 {
+    /// Lowercase hex SHA-256 of `user_context_data`, as stored in `user_context_hash`
+    pub fn hash_context(user_context_data: &str) -> String {
+        env::sha256_array(user_context_data.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+impl From<VerificationV1> for VerificationV2 {
+    fn from(v: VerificationV1) -> Self {
+        Self {
+            user_context_hash: Self::hash_context(&v.user_context_data),
+            near_account_id: v.near_account_id,
+            verified_at: v.verified_at,
+            user_context_data: v.user_context_data,
+            context_storage: ContextStorage::Full,
+        }
+    }
+}
+
 /// Type alias for the current verification version.
 ///
 /// Use this in application code for clarity. When the current version changes,
 /// update this alias to point to the new struct (along with migration logic
 /// in `VersionedVerification::into_current()`).
-pub type Verification = VerificationV1;
+pub type Verification = VerificationV2;
 
 impl From<&VerificationV1> for VerificationSummary {
     fn from(v: &VerificationV1) -> Self {
@@ -129,11 +337,20 @@ impl From<&VerificationV1> for VerificationSummary {
     }
 }
 
+impl From<&VerificationV2> for VerificationSummary {
+    fn from(v: &VerificationV2) -> Self {
+        Self {
+            near_account_id: v.near_account_id.clone(),
+            verified_at: v.verified_at,
+        }
+    }
+}
+
 impl From<&VersionedVerification> for VerificationSummary {
     fn from(v: &VersionedVerification) -> Self {
         match v {
             VersionedVerification::V1(v) => Self::from(v),
-            // VersionedVerification::V2(v) => Self::from(v),
+            VersionedVerification::V2(v) => Self::from(v),
         }
     }
 }
@@ -157,10 +374,18 @@ impl From<&VersionedVerification> for VerificationSummary {
 /// | Method | Recommended Gas |
 /// |--------|-----------------|
 /// | `is_verified` | 5 TGas |
+/// | `is_account_verified_since` | 5 TGas |
 /// | `get_verification` | 8 TGas |
 /// | `get_full_verification` | 15 TGas |
+/// | `get_linked_key` | 5 TGas |
 /// | `are_verified(10)` | 8 TGas |
 /// | `get_verifications(10)` | 12 TGas |
+/// | `list_verifications(100)` | 50 TGas |
+/// | `list_verifications_before(100)` | 40 TGas |
+/// | `get_statistics` | 8 TGas |
+/// | `interface_version` | 3 TGas |
+/// | `get_membership_diffs(100)` | 40 TGas |
+/// | `get_events(100)` | 30 TGas |
 /// | Callback overhead | 5 TGas |
 #[ext_contract(ext_verified_accounts)]
 pub trait VerifiedAccountsInterface {
@@ -172,6 +397,18 @@ pub trait VerifiedAccountsInterface {
     /// This is the most gas-efficient method.
     fn is_verified(&self, account_id: AccountId) -> bool;
 
+    /// Check if an account has been verified for at least `min_verified_duration_ns`.
+    ///
+    /// **Use this for:** Requiring accounts to have been citizens for N days before
+    /// voting or claiming, so freshly verified accounts cannot act immediately.
+    ///
+    /// Returns `false` if the account is not verified. Since interface 1.3.0.
+    fn is_account_verified_since(
+        &self,
+        account_id: AccountId,
+        min_verified_duration_ns: u64,
+    ) -> bool;
+
     /// Get verification summary.
     ///
     /// **Use this for:** Most cross-contract calls that need verification details.
@@ -187,6 +424,15 @@ pub trait VerifiedAccountsInterface {
     /// Returns `None` if the account is not verified.
     fn get_full_verification(&self, account_id: AccountId) -> Option<Verification>;
 
+    /// Get the NEP-413 public key linked to a verified account.
+    ///
+    /// **Use this for:** Checking further signatures from the account against the key
+    /// it verified with (updated by the owner via `rotate_linked_key`).
+    ///
+    /// Returns `None` if the account is not verified or was verified before keys were
+    /// linked. Since interface 2.1.0.
+    fn get_linked_key(&self, account_id: AccountId) -> Option<PublicKey>;
+
     // ==================== Batch Queries (for DAO voting, etc.) ====================
 
     /// Check multiple accounts in one call.
@@ -209,13 +455,98 @@ pub trait VerifiedAccountsInterface {
     /// Note: Large batches may exceed gas limits. Recommended max: 100 accounts.
     fn get_verifications(&self, account_ids: Vec<AccountId>) -> Vec<Option<VerificationSummary>>;
 
+    // ==================== Paginated & Snapshot Queries ====================
+
+    /// Get a page of full verification records in verification order.
+    ///
+    /// **Use this for:** Syncing the verified set into another contract or indexer.
+    ///
+    /// `limit` is capped at 100. Keep paging from `next_index` until it is `None`.
+    fn list_verifications(&self, from_index: u32, limit: u32) -> Page<Verification>;
+
+    /// Get summaries of accounts verified strictly before `timestamp` (nanoseconds).
+    ///
+    /// **Use this for:** Electorate snapshots (e.g. "citizens as of proposal creation").
+    ///
+    /// Pagination indexes the full verification list, so pages may be short;
+    /// keep paging from `next_index` until it is `None`.
+    fn list_verifications_before(
+        &self,
+        timestamp: u64,
+        from_index: u32,
+        limit: u32,
+    ) -> Page<VerificationSummary>;
+
     // ==================== Metadata ====================
 
     /// Get total number of verified accounts.
     fn get_verified_count(&self) -> u32;
 
+    /// Get aggregate statistics (count, first/last verification time, versions).
+    fn get_statistics(&self) -> VerificationStatistics;
+
+    /// Get the semver of this interface (see `INTERFACE_VERSION`).
+    ///
+    /// **Use this for:** Refusing to wire against an incompatible deployment.
+    fn interface_version(&self) -> String;
+
+    // ==================== Membership Sync ====================
+
+    /// Get the sequence number the next membership change will receive.
+    fn get_membership_seq(&self) -> u64;
+
+    /// Get up to `limit` (max 100) membership changes starting at `from_seq`.
+    ///
+    /// **Use this for:** Deterministically mirroring the citizen set to another chain.
+    fn get_membership_diffs(&self, from_seq: u64, limit: u32) -> Vec<MembershipDiff>;
+
+    // ==================== Event Log ====================
+
+    /// Get up to `limit` (max 100) of the last 10,000 events, starting at `from_seq`.
+    ///
+    /// **Use this for:** Recovering events an indexer missed while offline.
+    fn get_events(&self, from_seq: u64, limit: u32) -> Vec<IndexedEvent>;
+
     /// Check if contract is paused.
     ///
     /// When paused, no new verifications can be stored, but reads still work.
     fn is_paused(&self) -> bool;
 }
+
+// ==================== Consumer Callback Trait ====================
+
+/// Callback interface for contracts registered via `register_consumer`.
+///
+/// Implement `on_verification_changed` in consumer contracts (governance, bridge)
+/// to learn about status changes without polling. Calls are fire-and-forget:
+/// the oracle attaches 5 TGas and ignores the result, so keep handlers cheap and
+/// verify `env::predecessor_account_id()` is the oracle before acting.
+#[ext_contract(ext_verification_consumer)]
+pub trait VerificationConsumer {
+    /// Called after an account's verification status changes.
+    ///
+    /// `correlation_id` matches the `correlation_id` of the event that caused the change,
+    /// when there is one. Since interface 2.2.0; it is a trailing JSON field, so handlers
+    /// that omit it keep working because unknown argument fields are ignored.
+    fn on_verification_changed(
+        &mut self,
+        account_id: AccountId,
+        status: VerificationStatus,
+        correlation_id: Option<String>,
+    );
+}
+
+// ==================== Funder Trait ====================
+
+/// Top-up interface for the account registered via `set_funder`.
+///
+/// When a write leaves the oracle's available balance below its low balance threshold,
+/// the oracle calls `request_top_up` with the yoctoNEAR needed to restore twice the
+/// threshold, at most once per `top_up_request_interval_ns`. The call is fire-and-forget
+/// with 10 TGas; honor it by transferring `amount` to `env::predecessor_account_id()`
+/// after checking it is the oracle.
+#[ext_contract(ext_funder)]
+pub trait Funder {
+    /// Called when the oracle's available balance drops below its threshold.
+    fn request_top_up(&mut self, amount: U128);
+}
@@ -13,38 +13,204 @@
 //! - NEP-413 checks here are cryptographic only; backend must validate access keys via RPC,
 //!   enforce one-time challenges with TTLs, and bind challenges to this contract.
 //! - On-chain enforcement: signature validity, signature uniqueness, and account uniqueness.
+//!
+//! ## Errors
+//! - Every panic is `"<CODE>: <message>"` with a stable `ErrorCode` (see `errors.rs`).
+//! - `get_error_catalog()` lists all codes; clients should match on codes, not text.
+//!
+//! ## Consumers
+//! - Contracts registered via `register_consumer` (backend-approved) receive an
+//!   `on_verification_changed` callback whenever an account's status changes.
+//! - Notifications are fire-and-forget; a failing consumer never reverts the write.
+//! - Notifications and events of one verification share its `correlation_id`.
+//! - `store_verification` also accepts a one-off `callback` (contract, method) invoked with
+//!   `{"account_id": ..., "correlation_id": ...}` on success, for onboarding dapps that
+//!   would otherwise poll.
+//!
+//! ## Scoped Permissions
+//! - The backend wallet can `grant_permission` a secondary account a subset of the
+//!   operational writes (`BackendMethod`) until an expiry timestamp.
+//! - Admin actions (rotation, grants, policies, allowlists) stay backend-wallet only.
+//!
+//! ## Audit Log
+//! - Admin actions (pause, rotation, grants, consumer and config changes) are appended to a
+//!   hash chain: each entry commits to the previous head, so history cannot be rewritten
+//!   without changing `get_audit_head()`.
+//!
+//! ## Data Minimization
+//! - With `set_hash_only_storage(true)`, new records keep only the SHA-256 of
+//!   `user_context_data`; the backend retains the full data off-chain. Every record carries
+//!   `user_context_hash` and a `context_storage` flag, so both modes can be audited alike.
+//! - A verified account can call `prune_my_proof_data()` to delete its own stored
+//!   `user_context_data`; the hash and timestamps remain, so it stays verified.
+//!
+//! ## Consent
+//! - The backend registers each terms-of-participation version by its SHA-256 with
+//!   `register_terms_version`; the latest one is current.
+//! - Once any version is registered, `NearSignatureData.terms_hash` must name a registered
+//!   version and appear in the signed challenge. `get_consent` reports it, flagging
+//!   accounts that consented to superseded terms.
+//!
+//! ## Recipient Policy
+//! - `RecipientPolicy` decides which NEP-413 `recipient` is accepted: this contract (default),
+//!   the signing account itself, or either one while wallets migrate.
+//!
+//! ## App Binding
+//! - `NearSignatureData.app_id` is prefixed to the signed NEP-413 message as `"[app_id] "`,
+//!   so signatures collected by other dapps cannot be replayed here.
+//! - Once the backend allowlists an app ID, every signature must carry an allowlisted one;
+//!   while the allowlist is empty `app_id` stays optional.
+//!
+//! ## Verification Queue
+//! - Users call `request_verification()` to leave an on-chain trace of a pending request.
+//!   The queue is bounded; the backend consumes entries as it processes them (storing a
+//!   verification also clears the entry) and expires stale ones.
+//!
+//! ## MPC Authorization
+//! - With an MPC public key configured (`set_mpc_public_key`), anyone may relay
+//!   `store_verification_mpc`: the write is authorized by a secp256k1 signature over
+//!   `sha256(borsh(MpcStorePayload))` instead of the predecessor, so the backend key can live
+//!   in chain-signature custody. Strictly increasing nonces prevent replay.
+//!
+//! ## Backend Rotation
+//! - `begin_rotation(new_wallet, grace_blocks)` authorizes both wallets so in-flight
+//!   transactions from the old wallet still land; the old wallet expires after the grace period.
+//! - `complete_rotation()` makes the new wallet the sole backend wallet.
 
 #![allow(clippy::too_many_arguments)]
 
-use near_sdk::assert_one_yocto;
+// Error codes (declared first so `ensure!` is in scope for the rest of the crate)
+#[macro_use]
+pub mod errors;
+
 use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
-use near_sdk::json_types::Base64VecU8;
+use near_sdk::json_types::{Base58CryptoHash, Base64VecU8, U128};
 use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::store::IterableMap;
-use near_sdk::{env, near, AccountId, BorshStorageKey, NearSchema, PanicOnDefault, PublicKey};
+use near_sdk::store::{IterableMap, IterableSet, LookupMap, LookupSet, Vector};
+use near_sdk::{
+    env, near, AccountId, BorshStorageKey, CryptoHash, Gas, GasWeight, NearSchema, NearToken,
+    PanicOnDefault, Promise, PublicKey,
+};
 
+// Citizen weight token views for token-weighted DAO policies
+pub mod citizen_weight;
 // Interface module for cross-contract calls
 pub mod interface;
+use errors::assert_one_yocto;
+pub use errors::{ErrorCatalogEntry, ErrorCode};
 pub use interface::{
-    ext_verified_accounts, Verification, VerificationSummary, VersionedVerification,
+    ext_funder, ext_verification_consumer, ext_verified_accounts, ContextStorage, IndexedEvent,
+    MembershipChange, MembershipDiff, Page, Verification, VerificationStatistics,
+    VerificationStatus, VerificationSummary, VersionedVerification, CURRENT_VERIFICATION_VERSION,
+    INTERFACE_VERSION,
 };
 
 /// Maximum length for string inputs
 const MAX_USER_CONTEXT_DATA_LEN: usize = 4096;
 
+/// Maximum length of an allowlisted app ID
+const MAX_APP_ID_LEN: usize = 64;
+
 /// Maximum accounts per batch query
 const MAX_BATCH_SIZE: usize = 100;
 
+/// Maximum operations per `execute_batch` call (bounds signature checks and notifications).
+/// The effective cap can be lower: see `ContractV2::max_batch_ops`.
+const MAX_BATCH_OPS: usize = 10;
+
+/// Gas a batch may commit to store operations and their detached promises, leaving the
+/// rest of the 300 TGas transaction limit for the call itself and `batch_executed`
+const BATCH_GAS_BUDGET: Gas = Gas::from_tgas(250);
+
+/// Gas one store operation burns in this contract (signature check, storage, events)
+const STORE_OP_GAS: Gas = Gas::from_tgas(10);
+
+/// Gas the caller pays per scheduled function call on top of its attached gas
+/// (receipt and action send fees plus the prepaid execution fees)
+const PROMISE_OVERHEAD_GAS: Gas = Gas::from_tgas(5);
+
+/// Maximum records returned per paginated query
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of registered consumer contracts (bounds notification fan-out)
+const MAX_CONSUMERS: u32 = 10;
+
+/// Gas attached to each `on_verification_changed` consumer notification
+const CONSUMER_NOTIFICATION_GAS: Gas = Gas::from_tgas(5);
+
+/// Gas attached to a `store_verification` completion callback
+const COMPLETION_CALLBACK_GAS: Gas = Gas::from_tgas(5);
+
+/// Maximum length of a completion callback method name (NEAR runtime limit)
+const MAX_CALLBACK_METHOD_LEN: usize = 256;
+
+/// Number of recent events retained in the on-chain event log (ring buffer)
+const EVENT_LOG_CAPACITY: u32 = 10_000;
+
+/// Maximum pending `request_verification` entries (bounds the queue the backend drains)
+const MAX_VERIFICATION_QUEUE_LEN: u32 = 1_000;
+
+/// Deposit `request_verification` requires, refunded when the request leaves the queue.
+/// Covers the entry's storage, so the contract does not pay for requests.
+const VERIFICATION_REQUEST_DEPOSIT: NearToken = NearToken::from_millinear(10);
+
+/// Maximum length of a profile avatar CID
+const MAX_PROFILE_AVATAR_CID_LEN: usize = 128;
+
+/// Maximum number of links in a member profile
+const MAX_PROFILE_LINKS: usize = 5;
+
+/// Maximum length of a single profile link
+const MAX_PROFILE_LINK_LEN: usize = 256;
+
+/// Maximum verifications a single account can be credited for referring
+const MAX_REFERRALS_PER_ACCOUNT: u32 = 100;
+
+/// Default available balance below which writes emit `low_balance_warning`
+const DEFAULT_LOW_BALANCE_THRESHOLD: NearToken = NearToken::from_near(5);
+
+/// Pessimistic storage bytes of one verification with the full 4 KiB of context and
+/// its index, membership, event, consent and key entries. Used by the capacity estimate
+/// until there are verifications to average storage over.
+const MAX_VERIFICATION_STORAGE_BYTES: u64 = 6_000;
+
+/// Minimum time between `request_top_up` calls to the funder (24 hours)
+const TOP_UP_REQUEST_INTERVAL_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+/// Gas attached to a `request_top_up` call
+const TOP_UP_REQUEST_GAS: Gas = Gas::from_tgas(10);
+
+/// Time without a backend `heartbeat` after which `get_backend_liveness` reports stale (7 days)
+const BACKEND_LIVENESS_WINDOW_NS: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
+
+/// Version of the `get_config()` document layout; bump when fields are removed or renamed
+const CONFIG_VERSION: u8 = 1;
+
 /// Storage key prefixes for collections.
 /// IMPORTANT: These must remain constant across versions to preserve data.
 #[derive(BorshStorageKey, BorshSerialize)]
 #[borsh(crate = "near_sdk::borsh")]
 pub enum StorageKey {
     Accounts,
+    Consumers,
+    MembershipLog,
+    EventLog,
+    AllowedAppIds,
+    Permissions,
+    AuditLog,
+    VerificationQueue,
+    TermsVersions,
+    Consents,
+    Profiles,
+    Referrers,
+    ReferralCounts,
+    LinkedKeys,
+    LinkedKeyNonces,
+    WeightDelegations,
 }
 
 /// NEAR signature data
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, NearSchema)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, NearSchema)]
 #[abi(json)]
 #[serde(crate = "near_sdk::serde")]
 #[borsh(crate = "near_sdk::borsh")]
@@ -55,6 +221,177 @@ pub struct NearSignatureData {
     pub challenge: String,
     pub nonce: Base64VecU8,
     pub recipient: AccountId,
+    /// App the signature was collected for; prefixed to the signed message when set
+    #[serde(default)]
+    pub app_id: Option<String>,
+    /// SHA-256 (hex) of the terms version the user accepted; must appear in `challenge`
+    #[serde(default)]
+    pub terms_hash: Option<String>,
+    /// Verified citizen credited with referring this account. Not part of the NEP-413
+    /// message; authenticated by the caller check, or by the MPC signature on the MPC path.
+    #[serde(default)]
+    pub referred_by: Option<AccountId>,
+}
+
+impl NearSignatureData {
+    /// Message the wallet actually signed: `challenge`, prefixed with `"[app_id] "` if set
+    pub fn signed_message(&self) -> String {
+        match &self.app_id {
+            Some(app_id) => format!("[{}] {}", app_id, self.challenge),
+            None => self.challenge.clone(),
+        }
+    }
+
+    /// ID correlating every event and call caused by storing this signature:
+    /// hex SHA-256 of the transaction signer followed by the NEP-413 nonce
+    pub fn correlation_id(&self) -> String {
+        let mut preimage = env::signer_account_id().as_bytes().to_vec();
+        preimage.extend_from_slice(&self.nonce.0);
+        env::sha256_array(&preimage)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+/// Backend write applied by `execute_batch`.
+///
+/// Append new variants only (e.g. revoke/freeze once those writes exist).
+#[derive(Serialize, Deserialize, NearSchema)]
+#[abi(json)]
+#[serde(crate = "near_sdk::serde", tag = "op", rename_all = "snake_case")]
+pub enum BackendOp {
+    /// Same arguments and checks as `store_verification`
+    Store {
+        near_account_id: AccountId,
+        signature_data: NearSignatureData,
+        user_context_data: String,
+        #[serde(default)]
+        callback: Option<(AccountId, String)>,
+    },
+}
+
+/// Outcome of one `BackendOp`, returned by `execute_batch` and logged in `batch_executed`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BackendOpResult {
+    /// Position of the operation in the batch
+    pub index: u32,
+    /// Operation name (matches the `op` tag of `BackendOp`)
+    pub op: String,
+    /// Account the operation applied to
+    pub near_account_id: AccountId,
+    /// `correlation_id` of the verification the operation stored, if any
+    pub correlation_id: Option<String>,
+}
+
+/// Which NEP-413 `recipient` a signature must name, set via `set_recipient_policy`
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    NearSchema,
+)]
+#[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
+#[borsh(crate = "near_sdk::borsh")]
+pub enum RecipientPolicy {
+    /// Recipient must be this contract (wallets that enforce recipient == dapp contract)
+    Contract,
+    /// Recipient must be the signing account (self-recipient convention)
+    Signer,
+    /// Either of the above; use while migrating between conventions
+    Either,
+}
+
+impl RecipientPolicy {
+    /// Whether `recipient` is acceptable for a signature by `signer`
+    pub fn allows(self, recipient: &AccountId, signer: &AccountId) -> bool {
+        let is_contract = *recipient == env::current_account_id();
+        let is_signer = recipient == signer;
+        match self {
+            Self::Contract => is_contract,
+            Self::Signer => is_signer,
+            Self::Either => is_contract || is_signer,
+        }
+    }
+
+    /// Human-readable description of the accepted recipient, for error messages
+    pub fn expected_recipient(self) -> &'static str {
+        match self {
+            Self::Contract => "contract account",
+            Self::Signer => "signing account",
+            Self::Either => "contract account or signing account",
+        }
+    }
+}
+
+/// Stored membership log entry; its sequence number is implied by position
+/// (`membership_log_base + index`), keeping entries compact.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MembershipLogEntry {
+    pub account_id: AccountId,
+    pub change: MembershipChange,
+    /// Block timestamp (nanoseconds) of the change
+    pub timestamp: u64,
+}
+
+/// Stored event log entry; its sequence number is implied by its ring position
+/// relative to `next_event_seq`.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct EventLogEntry {
+    pub kind: String,
+    /// SHA-256 of the emitted `EVENT_JSON:` log line
+    pub payload_hash: CryptoHash,
+    pub block_height: u64,
+}
+
+/// Stored audit log entry; `seq` is its index in `audit_log`
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct AuditLogEntry {
+    pub kind: String,
+    /// Chain head before this entry (all zeros for the first entry)
+    pub prev_hash: CryptoHash,
+    /// SHA-256 of the emitted `EVENT_JSON:` log line
+    pub payload_hash: CryptoHash,
+    pub block_height: u64,
+}
+
+impl AuditLogEntry {
+    /// Chain hash: `sha256(prev_hash || payload_hash || block_height as u64 LE)`
+    pub fn entry_hash(&self) -> CryptoHash {
+        let mut preimage = Vec::with_capacity(72);
+        preimage.extend_from_slice(&self.prev_hash);
+        preimage.extend_from_slice(&self.payload_hash);
+        preimage.extend_from_slice(&self.block_height.to_le_bytes());
+        env::sha256_array(&preimage)
+    }
+}
+
+/// Payload an MPC signature authorizes in `store_verification_mpc`.
+///
+/// The signed digest is `sha256(borsh(payload))`.
+#[derive(BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MpcStorePayload {
+    /// This contract, so signatures cannot be replayed against other deployments
+    pub contract_id: AccountId,
+    /// Must exceed the last nonce the contract accepted
+    pub nonce: u64,
+    pub near_account_id: AccountId,
+    /// The user's NEP-413 signature bytes, binding the payload to one signed challenge
+    pub user_signature: Vec<u8>,
+    /// SHA-256 of `user_context_data`
+    pub user_context_hash: CryptoHash,
+    /// `signature_data.referred_by`, which the user's NEP-413 signature does not cover
+    pub referred_by: Option<AccountId>,
 }
 
 /// NEP-413 Payload structure
@@ -72,6 +409,16 @@ pub struct Nep413Payload {
 #[serde(crate = "near_sdk::serde")]
 pub struct VerificationStoredEvent {
     pub near_account_id: AccountId,
+    /// Shared by every event and call caused by this verification
+    pub correlation_id: String,
+}
+
+/// Event emitted after a successful `execute_batch`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BatchExecutedEvent {
+    pub by: AccountId,
+    pub results: Vec<BackendOpResult>,
 }
 
 /// Event emitted when contract is paused
@@ -96,14 +443,512 @@ pub struct BackendWalletUpdatedEvent {
     pub new_wallet: AccountId,
 }
 
-/// Helper to emit JSON events in NEAR standard format
-fn emit_event<T: Serialize>(event_name: &str, data: &T) {
-    match near_sdk::serde_json::to_string(data) {
-        Ok(json) => {
-            env::log_str(&format!("EVENT_JSON:{{\"standard\":\"near-verified-accounts\",\"version\":\"1.0.0\",\"event\":\"{}\",\"data\":{}}}", event_name, json));
+/// Write method that can be delegated with `grant_permission`
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    NearSchema,
+)]
+#[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
+#[borsh(crate = "near_sdk::borsh")]
+pub enum BackendMethod {
+    /// `store_verification` and `store` ops in `execute_batch`
+    StoreVerification,
+    Pause,
+    Unpause,
+    RegisterConsumer,
+    UnregisterConsumer,
+}
+
+/// Scoped authorization held by a secondary account, returned by `get_permission()`
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema,
+)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct PermissionGrant {
+    pub methods: Vec<BackendMethod>,
+    /// Block timestamp (nanoseconds) after which the grant is ignored
+    pub expires_at: u64,
+}
+
+/// Event emitted when a scoped permission is granted or replaced
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PermissionGrantedEvent {
+    pub account_id: AccountId,
+    pub methods: Vec<BackendMethod>,
+    pub expires_at: u64,
+    pub by: AccountId,
+}
+
+/// Event emitted when a scoped permission is revoked
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PermissionRevokedEvent {
+    pub account_id: AccountId,
+    pub by: AccountId,
+}
+
+/// Event emitted when a backend wallet rotation begins
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BackendRotationStartedEvent {
+    pub old_wallet: AccountId,
+    pub new_wallet: AccountId,
+    pub expires_at_block: u64,
+}
+
+/// Event emitted when a pending backend wallet rotation is cancelled
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BackendRotationCancelledEvent {
+    /// Wallet that stays the backend wallet
+    pub backend_wallet: AccountId,
+    /// Wallet whose authorization was withdrawn
+    pub new_wallet: AccountId,
+}
+
+/// In-progress backend wallet rotation, returned by `get_backend_rotation()`
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema,
+)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct BackendRotation {
+    /// Wallet that becomes the backend wallet on completion
+    pub new_wallet: AccountId,
+    /// Last block height at which the old wallet is still accepted
+    pub expires_at_block: u64,
+}
+
+/// Last backend `heartbeat`
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema,
+)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct BackendHeartbeat {
+    /// Block height of the heartbeat
+    pub block_height: u64,
+    /// Unix timestamp (nanoseconds) of the heartbeat
+    pub timestamp: u64,
+}
+
+/// Backend liveness signal, returned by `get_backend_liveness()`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BackendLiveness {
+    /// Last heartbeat, if the backend has ever sent one
+    pub last_heartbeat: Option<BackendHeartbeat>,
+    /// Nanoseconds since the last heartbeat
+    pub stale_for_ns: Option<u64>,
+    /// No heartbeat within `backend_liveness_window_ns`
+    pub stale: bool,
+}
+
+/// Event emitted when any value reported by `get_config()` changes
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ConfigUpdatedEvent {
+    /// Name of the changed `ContractConfig` field
+    pub field: String,
+    pub by: AccountId,
+}
+
+/// Every tunable parameter of a deployment, returned by `get_config()`.
+///
+/// Fields may be appended without bumping `config_version`; removals or renames bump it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ContractConfig {
+    /// Layout version of this document
+    pub config_version: u8,
+    /// Crate version of the deployed code
+    pub code_version: String,
+    /// Semver of the cross-contract interface
+    pub interface_version: String,
+    /// Contract state version
+    pub state_version: u8,
+    /// Verification record version written by new verifications
+    pub record_version: u8,
+    /// Account authorized to write to this contract
+    pub backend_wallet: AccountId,
+    /// Maximum `user_context_data` length in bytes
+    pub max_user_context_data_len: u32,
+    /// Maximum accounts per batch query
+    pub max_batch_size: u32,
+    /// Maximum operations per `execute_batch` call with the current consumers, weight DAO
+    /// and funder; lower than 10 when their notification gas would not fit one transaction
+    pub max_batch_ops: u32,
+    /// Maximum records per paginated query
+    pub max_page_size: u32,
+    /// Maximum number of registered consumers
+    pub max_consumers: u32,
+    /// Gas (in TGas) attached to each consumer notification
+    pub consumer_notification_tgas: u64,
+    /// Number of recent events retained by `get_events`
+    pub event_log_capacity: u32,
+    /// Maximum pending `request_verification` entries
+    pub max_verification_queue_len: u32,
+    /// Deposit (yoctoNEAR) `request_verification` requires, refunded when the request is removed
+    pub verification_request_deposit: U128,
+    /// Maximum verifications a single account can be credited for referring
+    pub max_referrals_per_account: u32,
+    /// Available balance (yoctoNEAR) below which writes emit `low_balance_warning`
+    pub low_balance_threshold: U128,
+    /// Time without a backend heartbeat after which the backend is reported stale
+    pub backend_liveness_window_ns: u64,
+    /// Account asked via `request_top_up` when the balance is low; `None` disables top-ups
+    pub funder: Option<AccountId>,
+    /// Minimum time between top-up requests, in nanoseconds
+    pub top_up_request_interval_ns: u64,
+    /// SputnikDAO that uses this contract as its staking contract; `None` disables delegation
+    pub weight_dao: Option<AccountId>,
+    /// App IDs accepted in signature data; empty means app binding is optional
+    pub allowed_app_ids: Vec<String>,
+    /// Which NEP-413 recipient signatures must name
+    pub recipient_policy: RecipientPolicy,
+    /// Whether new records store only the hash of `user_context_data`
+    pub hash_only_storage: bool,
+    /// SHA-256 of the current terms version; `None` until one is registered
+    pub current_terms_hash: Option<String>,
+    /// secp256k1 key authorizing `store_verification_mpc`; `None` disables MPC writes
+    pub mpc_public_key: Option<Base64VecU8>,
+}
+
+/// Collection exported by `export_state_chunk`
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
+pub enum StateChunkKind {
+    /// Borsh `Vec<(AccountId, VersionedVerification)>` in storage order
+    Accounts,
+    /// Borsh `Vec<AccountId>` in storage order
+    Consumers,
+}
+
+impl StateChunkKind {
+    /// Every kind, in the order `get_state_root()` combines them
+    pub const ALL: [StateChunkKind; 2] = [Self::Accounts, Self::Consumers];
+}
+
+/// Borsh-encoded slice of contract state, returned by `export_state_chunk`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StateChunk {
+    pub kind: StateChunkKind,
+    pub from_index: u32,
+    /// Number of entries in `data`
+    pub count: u32,
+    /// Borsh-serialized entries (see `StateChunkKind`)
+    pub data: Base64VecU8,
+    /// SHA-256 of `data`
+    pub hash: Base58CryptoHash,
+}
+
+/// Digest of the full exportable state, returned by `get_state_root`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StateRoot {
+    /// SHA-256 over the concatenated chunk hashes of every kind, in `StateChunkKind::ALL` order
+    pub root: Base58CryptoHash,
+    /// Chunk size the root was computed with; export with this `limit` to reproduce it
+    pub chunk_size: u32,
+    /// Total number of chunks across all kinds
+    pub chunk_count: u32,
+}
+
+/// Inconsistency found by `verify_record_integrity`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
+pub enum IntegrityIssue {
+    /// No record is stored for the account
+    NotFound,
+    /// Record's `near_account_id` differs from the key it is stored under
+    AccountIdMismatch { stored: AccountId },
+    /// `user_context_data` exceeds `MAX_USER_CONTEXT_DATA_LEN`
+    ContextDataTooLong { len: u32 },
+    /// `verified_at` is zero or later than the current block
+    InvalidTimestamp { verified_at: u64 },
+    /// Stored context data does not match `user_context_hash` or `context_storage`
+    ContextHashMismatch,
+}
+
+/// Result of checking one record, returned by the integrity views
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct IntegrityReport {
+    pub account_id: AccountId,
+    /// Empty when the record is consistent
+    pub issues: Vec<IntegrityIssue>,
+}
+
+/// Audit log entry returned by `get_audit_entries`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AuditEntry {
+    pub seq: u64,
+    /// Event name of the admin action
+    pub kind: String,
+    pub prev_hash: Base58CryptoHash,
+    pub payload_hash: Base58CryptoHash,
+    pub block_height: u64,
+    /// `sha256(prev_hash || payload_hash || block_height as u64 LE)`; the next entry's `prev_hash`
+    pub entry_hash: Base58CryptoHash,
+}
+
+/// Current end of the audit chain, returned by `get_audit_head`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AuditHead {
+    /// Number of entries in the chain
+    pub length: u64,
+    /// `entry_hash` of the last entry (all zeros when empty)
+    pub head: Base58CryptoHash,
+}
+
+/// Registered terms-of-participation version, returned by `get_terms_versions()`
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema,
+)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct TermsVersion {
+    /// Lowercase hex SHA-256 of the terms document
+    pub terms_hash: String,
+    /// Block timestamp (nanoseconds) of registration
+    pub registered_at: u64,
+}
+
+/// Stored consent; the terms hash is looked up from `terms_versions`
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ConsentRecord {
+    /// Index into `terms_versions`
+    pub terms_version: u32,
+    /// Block timestamp (nanoseconds) of the verification that recorded consent
+    pub consented_at: u64,
+}
+
+/// Consent provenance of a verified account, returned by `get_consent`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Consent {
+    pub terms_hash: String,
+    /// Index of the accepted version in `get_terms_versions()`
+    pub terms_version: u32,
+    pub consented_at: u64,
+    /// Whether newer terms have been registered since
+    pub superseded: bool,
+}
+
+/// Opt-in public profile of a verified account, set with `set_profile`
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema,
+)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MemberProfile {
+    /// Lowercase hex SHA-256 of the display handle; the handle itself stays off-chain
+    pub handle_hash: Option<String>,
+    /// Content identifier (e.g. IPFS CID) of the avatar image
+    pub avatar_cid: Option<String>,
+    /// Profile links, at most `MAX_PROFILE_LINKS`
+    pub links: Vec<String>,
+}
+
+/// Verified member with their opt-in profile, returned by `get_member_profile`
+#[derive(Serialize, Deserialize, Clone, Debug, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MemberInfo {
+    pub verification: VerificationSummary,
+    /// `None` until the member calls `set_profile`
+    pub profile: Option<MemberProfile>,
+}
+
+/// Event emitted when a member sets or clears their profile
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProfileUpdatedEvent {
+    pub account_id: AccountId,
+    pub cleared: bool,
+}
+
+/// Event emitted when an account rotates its linked public key
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LinkedKeyRotatedEvent {
+    pub account_id: AccountId,
+    pub public_key: PublicKey,
+}
+
+/// Event emitted when a verification is stored with a referrer
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ReferralRecordedEvent {
+    pub account_id: AccountId,
+    pub referred_by: AccountId,
+    pub correlation_id: String,
+}
+
+/// Event emitted when a write leaves the available balance below the threshold
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LowBalanceWarningEvent {
+    pub available_balance: U128,
+    pub threshold: U128,
+    pub estimated_verifications_remaining: u64,
+    /// `correlation_id` of the verification whose write triggered the warning, if any
+    pub correlation_id: Option<String>,
+}
+
+/// Event emitted when the funder is asked to top up the contract
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TopUpRequestedEvent {
+    pub funder: AccountId,
+    pub amount: U128,
+    /// `correlation_id` of the verification whose write triggered the request, if any
+    pub correlation_id: Option<String>,
+}
+
+/// Event emitted when an account's citizen weight is delegated in the weight DAO
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CitizenWeightDelegatedEvent {
+    pub account_id: AccountId,
+    pub dao: AccountId,
+    /// `correlation_id` of the verification that delegated the weight; `None` for syncs
+    pub correlation_id: Option<String>,
+}
+
+/// Balance and storage headroom of the contract, returned by `get_capacity_estimate()`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CapacityEstimate {
+    /// Total account balance (yoctoNEAR)
+    pub account_balance: U128,
+    /// Bytes of storage in use
+    pub storage_usage: u64,
+    /// Storage bytes assumed per verification: storage usage averaged over the verified
+    /// count, or a pessimistic full-context record size while nothing is verified
+    pub bytes_per_verification: u64,
+    /// Balance not locked by storage staking (yoctoNEAR)
+    pub available_balance: U128,
+    /// Available balance below which writes emit `low_balance_warning`
+    pub low_balance_threshold: U128,
+    /// Verifications the available balance can still pay storage for (estimate)
+    pub estimated_verifications_remaining: u64,
+}
+
+impl CapacityEstimate {
+    /// Estimate capacity from the current account balance and storage usage.
+    ///
+    /// Averaging all storage over the verified count includes the contract code and
+    /// fixed state, so the estimate errs low while the set is small.
+    fn current(low_balance_threshold: NearToken, verified_count: u64) -> Self {
+        let account_balance = env::account_balance();
+        let storage_usage = env::storage_usage();
+        let locked = env::storage_byte_cost().saturating_mul(u128::from(storage_usage));
+        let available = account_balance.saturating_sub(locked);
+        let bytes_per_verification = storage_usage
+            .checked_div(verified_count)
+            .filter(|bytes| *bytes > 0)
+            .unwrap_or(MAX_VERIFICATION_STORAGE_BYTES);
+        let verification_cost = env::storage_byte_cost()
+            .saturating_mul(u128::from(bytes_per_verification))
+            .as_yoctonear();
+        let remaining = available
+            .as_yoctonear()
+            .checked_div(verification_cost)
+            .unwrap_or(0);
+        Self {
+            account_balance: U128(account_balance.as_yoctonear()),
+            storage_usage,
+            bytes_per_verification,
+            available_balance: U128(available.as_yoctonear()),
+            low_balance_threshold: U128(low_balance_threshold.as_yoctonear()),
+            estimated_verifications_remaining: u64::try_from(remaining).unwrap_or(u64::MAX),
         }
+    }
+}
+
+/// Event emitted when a terms version is registered
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TermsVersionRegisteredEvent {
+    pub terms_version: u32,
+    pub terms_hash: String,
+    pub by: AccountId,
+}
+
+/// Event emitted when an account prunes its stored context data
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProofDataPrunedEvent {
+    pub account_id: AccountId,
+}
+
+/// Event emitted when a user queues a verification request
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct VerificationRequestedEvent {
+    pub account_id: AccountId,
+    pub requested_at: u64,
+}
+
+/// Event emitted when the backend removes queued verification requests
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct VerificationRequestsRemovedEvent {
+    pub account_ids: Vec<AccountId>,
+    /// `"consumed"` or `"expired"`
+    pub reason: String,
+    pub by: AccountId,
+}
+
+/// Queued verification request, returned by `get_verification_request`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, NearSchema)]
+#[serde(crate = "near_sdk::serde")]
+pub struct QueuedRequest {
+    /// Number of requests queued before this one
+    pub position: u32,
+    /// Block timestamp (nanoseconds) of the request
+    pub requested_at: u64,
+}
+
+/// Event emitted when a consumer contract is registered
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ConsumerRegisteredEvent {
+    pub contract_id: AccountId,
+}
+
+/// Event emitted when a consumer contract is unregistered
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ConsumerUnregisteredEvent {
+    pub contract_id: AccountId,
+}
+
+/// Challenge `account_id` must sign with the new key to call `rotate_linked_key`
+pub fn linked_key_challenge(account_id: &AccountId) -> String {
+    format!("Link key to {}", account_id)
+}
+
+/// Format a JSON event in NEAR standard format
+fn format_event<T: Serialize>(event_name: &str, data: &T) -> Option<String> {
+    match near_sdk::serde_json::to_string(data) {
+        Ok(json) => Some(format!("EVENT_JSON:{{\"standard\":\"near-verified-accounts\",\"version\":\"1.0.0\",\"event\":\"{}\",\"data\":{}}}", event_name, json)),
         Err(e) => {
             env::log_str(&format!("Failed to emit event {}: {:?}", event_name, e));
+            None
         }
     }
 }
@@ -117,15 +962,17 @@ fn emit_event<T: Serialize>(event_name: &str, data: &T) {
 #[derive(PanicOnDefault)]
 #[near(contract_state)]
 pub enum VersionedContract {
-    /// V1: Original contract state (current production version)
+    /// V1: Original contract state (production version)
     V1(ContractV1),
+    /// V2: Adds consumer registry (current version)
+    V2(ContractV2),
     // Future versions append here:
-    // V2(ContractV2),  // 0x01 - example: adds rate limiting
+    // V3(ContractV3),
 }
 
 /// Contract state version 1 (SumSub-based verification).
 ///
-/// When adding fields, create `ContractV2` instead of modifying this struct.
+/// Do NOT modify this struct - create new versions instead.
 #[near]
 pub struct ContractV1 {
     /// Account authorized to write to this contract
@@ -136,20 +983,138 @@ pub struct ContractV1 {
     pub paused: bool,
 }
 
+/// Contract state version 2 - adds the consumer registry and membership diff log.
+///
+/// When adding fields after V2 ships, create `ContractV3` instead of modifying this struct.
+#[near]
+pub struct ContractV2 {
+    /// Account authorized to write to this contract
+    pub backend_wallet: AccountId,
+    /// Map of NEAR accounts to their verification records (versioned format)
+    pub verifications: IterableMap<AccountId, VersionedVerification>,
+    /// Whether the contract is paused
+    pub paused: bool,
+    /// Contracts notified via `on_verification_changed` on status changes
+    pub consumers: IterableSet<AccountId>,
+    /// Append-only log of membership changes for cross-chain relayers
+    pub membership_log: Vector<MembershipLogEntry>,
+    /// Sequence number of the first logged entry. Earlier sequence numbers are the
+    /// accounts verified before the V2 upgrade, in verification order.
+    pub membership_log_base: u64,
+    /// Ring buffer of the last `EVENT_LOG_CAPACITY` emitted events
+    pub event_log: Vector<EventLogEntry>,
+    /// Sequence number the next emitted event will receive
+    pub next_event_seq: u64,
+    /// Pending backend wallet rotation, if any
+    pub backend_rotation: Option<BackendRotation>,
+    /// App IDs accepted in `NearSignatureData.app_id`; empty means app binding is optional
+    pub allowed_app_ids: IterableSet<String>,
+    /// Which NEP-413 recipient signatures must name
+    pub recipient_policy: RecipientPolicy,
+    /// Scoped write permissions held by secondary accounts
+    pub permissions: IterableMap<AccountId, PermissionGrant>,
+    /// Append-only hash chain of admin actions
+    pub audit_log: Vector<AuditLogEntry>,
+    /// `entry_hash` of the last audit entry (all zeros when empty)
+    pub audit_head: CryptoHash,
+    /// Pending user verification requests: account -> request timestamp (nanoseconds)
+    pub verification_queue: IterableMap<AccountId, u64>,
+    /// Whether new records store only the hash of `user_context_data`
+    pub hash_only_storage: bool,
+    /// Registered terms versions, oldest first; the last one is current
+    pub terms_versions: Vector<TermsVersion>,
+    /// Terms version each account accepted when verified
+    pub consents: LookupMap<AccountId, ConsentRecord>,
+    /// Uncompressed secp256k1 key (64 bytes, no prefix) authorizing `store_verification_mpc`
+    pub mpc_public_key: Option<Vec<u8>>,
+    /// Last nonce accepted by `store_verification_mpc`
+    pub mpc_nonce: u64,
+    /// Opt-in member profiles, keyed by verified account
+    pub profiles: LookupMap<AccountId, MemberProfile>,
+    /// Referrer credited for each account verified with `referred_by`
+    pub referrers: LookupMap<AccountId, AccountId>,
+    /// Number of verifications each referrer has been credited for
+    pub referral_counts: LookupMap<AccountId, u32>,
+    /// Available balance below which writes emit `low_balance_warning`
+    pub low_balance_threshold: NearToken,
+    /// Account asked via `request_top_up` when the balance is low
+    pub funder: Option<AccountId>,
+    /// Timestamp of the last `request_top_up` call (nanoseconds)
+    pub last_top_up_request_at: Option<u64>,
+    /// NEP-413 public key linked to each account, set on verification and by `rotate_linked_key`
+    pub linked_keys: LookupMap<AccountId, PublicKey>,
+    /// NEP-413 nonces consumed by `rotate_linked_key`
+    pub linked_key_nonces: LookupSet<Vec<u8>>,
+    /// Last backend `heartbeat`
+    pub last_heartbeat: Option<BackendHeartbeat>,
+    /// SputnikDAO that verified accounts delegate citizen weight to
+    pub weight_dao: Option<AccountId>,
+    /// DAO each account's citizen weight was delegated to
+    pub weight_delegations: LookupMap<AccountId, AccountId>,
+}
+
 /// Type alias for the current contract version.
 /// Update this when changing the current production version.
-pub type Contract = ContractV1;
+pub type Contract = ContractV2;
+
+impl From<ContractV1> for ContractV2 {
+    fn from(v1: ContractV1) -> Self {
+        Self {
+            backend_wallet: v1.backend_wallet,
+            paused: v1.paused,
+            consumers: IterableSet::new(StorageKey::Consumers),
+            membership_log_base: u64::from(v1.verifications.len()),
+            membership_log: Vector::new(StorageKey::MembershipLog),
+            event_log: Vector::new(StorageKey::EventLog),
+            next_event_seq: 0,
+            backend_rotation: None,
+            allowed_app_ids: IterableSet::new(StorageKey::AllowedAppIds),
+            recipient_policy: RecipientPolicy::Contract,
+            permissions: IterableMap::new(StorageKey::Permissions),
+            audit_log: Vector::new(StorageKey::AuditLog),
+            audit_head: [0; 32],
+            verification_queue: IterableMap::new(StorageKey::VerificationQueue),
+            hash_only_storage: false,
+            terms_versions: Vector::new(StorageKey::TermsVersions),
+            consents: LookupMap::new(StorageKey::Consents),
+            mpc_public_key: None,
+            mpc_nonce: 0,
+            profiles: LookupMap::new(StorageKey::Profiles),
+            referrers: LookupMap::new(StorageKey::Referrers),
+            referral_counts: LookupMap::new(StorageKey::ReferralCounts),
+            low_balance_threshold: DEFAULT_LOW_BALANCE_THRESHOLD,
+            funder: None,
+            last_top_up_request_at: None,
+            linked_keys: LookupMap::new(StorageKey::LinkedKeys),
+            linked_key_nonces: LookupSet::new(StorageKey::LinkedKeyNonces),
+            last_heartbeat: None,
+            weight_dao: None,
+            weight_delegations: LookupMap::new(StorageKey::WeightDelegations),
+            verifications: v1.verifications,
+        }
+    }
+}
 
 impl VersionedContract {
     /// Get mutable reference to current contract version, upgrading if necessary.
     ///
-    /// This method handles lazy migration from older versions to the current version.
-    /// When adding V2, this would migrate V1 -> V2 on first write.
+    /// Lazily migrates V1 to V2 on first write. Collections are moved, not copied,
+    /// so their storage prefixes (and data) are preserved.
     fn contract_mut(&mut self) -> &mut Contract {
+        if let Self::V1(v1) = self {
+            let v1 = ContractV1 {
+                backend_wallet: v1.backend_wallet.clone(),
+                verifications: std::mem::replace(
+                    &mut v1.verifications,
+                    IterableMap::new(StorageKey::Accounts),
+                ),
+                paused: v1.paused,
+            };
+            *self = Self::V2(ContractV2::from(v1));
+        }
         match self {
-            Self::V1(contract) => contract,
-            // Future versions: migrate to current
-            // Self::V2(contract) => contract,
+            Self::V2(contract) => contract,
+            Self::V1(_) => env::abort(),
         }
     }
 
@@ -161,7 +1126,7 @@ impl VersionedContract {
     fn verifications(&self) -> &IterableMap<AccountId, VersionedVerification> {
         match self {
             Self::V1(c) => &c.verifications,
-            // Self::V2(c) => &c.verifications,
+            Self::V2(c) => &c.verifications,
         }
     }
 
@@ -169,7 +1134,7 @@ impl VersionedContract {
     fn backend_wallet(&self) -> &AccountId {
         match self {
             Self::V1(c) => &c.backend_wallet,
-            // Self::V2(c) => &c.backend_wallet,
+            Self::V2(c) => &c.backend_wallet,
         }
     }
 
@@ -177,7 +1142,315 @@ impl VersionedContract {
     fn paused(&self) -> bool {
         match self {
             Self::V1(c) => c.paused,
-            // Self::V2(c) => c.paused,
+            Self::V2(c) => c.paused,
+        }
+    }
+
+    /// Get recipient policy (V1 only ever accepted the contract as recipient)
+    fn recipient_policy(&self) -> RecipientPolicy {
+        match self {
+            Self::V1(_) => RecipientPolicy::Contract,
+            Self::V2(c) => c.recipient_policy,
+        }
+    }
+
+    /// Get hash-only storage mode (V1 always stored full context data)
+    fn hash_only_storage(&self) -> bool {
+        match self {
+            Self::V1(_) => false,
+            Self::V2(c) => c.hash_only_storage,
+        }
+    }
+
+    /// Get the low balance warning threshold (V1 uses the default)
+    fn low_balance_threshold(&self) -> NearToken {
+        match self {
+            Self::V1(_) => DEFAULT_LOW_BALANCE_THRESHOLD,
+            Self::V2(c) => c.low_balance_threshold,
+        }
+    }
+
+    /// Get event log and the next event sequence number (V2+ only)
+    fn event_log(&self) -> Option<(&Vector<EventLogEntry>, u64)> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(c) => Some((&c.event_log, c.next_event_seq)),
+        }
+    }
+
+    /// Get membership log and its base sequence number (V2+ only)
+    fn membership_log(&self) -> Option<(&Vector<MembershipLogEntry>, u64)> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(c) => Some((&c.membership_log, c.membership_log_base)),
+        }
+    }
+
+    /// Get reference to the verification request queue (V2+ only)
+    fn verification_queue(&self) -> Option<&IterableMap<AccountId, u64>> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(c) => Some(&c.verification_queue),
+        }
+    }
+
+    /// Get reference to consumer registry (V2+ only)
+    fn consumers(&self) -> Option<&IterableSet<AccountId>> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(c) => Some(&c.consumers),
+        }
+    }
+}
+
+impl ContractV2 {
+    /// Whether `account` may act as the backend wallet.
+    ///
+    /// During a rotation the new wallet is always accepted and the old one only until
+    /// the grace period ends.
+    fn is_backend(&self, account: &AccountId) -> bool {
+        match &self.backend_rotation {
+            None => *account == self.backend_wallet,
+            Some(rotation) => {
+                *account == rotation.new_wallet
+                    || (*account == self.backend_wallet
+                        && env::block_height() <= rotation.expires_at_block)
+            }
+        }
+    }
+
+    /// Whether `account` may change configuration, permissions or the rotation itself.
+    ///
+    /// Stricter than `is_backend`: during the grace period only the current wallet
+    /// qualifies, so a pending wallet cannot make changes that outlive `cancel_rotation`.
+    /// Once the grace period ends only the new wallet qualifies.
+    fn is_admin(&self, account: &AccountId) -> bool {
+        match &self.backend_rotation {
+            Some(rotation) if env::block_height() > rotation.expires_at_block => {
+                *account == rotation.new_wallet
+            }
+            _ => *account == self.backend_wallet,
+        }
+    }
+
+    /// Gas one store operation can commit: its own execution plus the static gas of
+    /// every promise it may create (consumer notifications, weight delegation, callback).
+    fn store_op_gas(&self) -> Gas {
+        let notifications = CONSUMER_NOTIFICATION_GAS
+            .saturating_add(PROMISE_OVERHEAD_GAS)
+            .saturating_mul(u64::from(self.consumers.len()));
+        let delegation = if self.weight_dao.is_some() {
+            citizen_weight::DELEGATION_GAS
+                .saturating_add(PROMISE_OVERHEAD_GAS)
+                .saturating_mul(2)
+                .saturating_add(citizen_weight::DELEGATION_CALLBACK_GAS)
+                .saturating_add(PROMISE_OVERHEAD_GAS)
+        } else {
+            Gas::from_gas(0)
+        };
+        STORE_OP_GAS
+            .saturating_add(notifications)
+            .saturating_add(delegation)
+            .saturating_add(COMPLETION_CALLBACK_GAS)
+            .saturating_add(PROMISE_OVERHEAD_GAS)
+            // refund of the account's queued request deposit
+            .saturating_add(PROMISE_OVERHEAD_GAS)
+    }
+
+    /// Operations `execute_batch` accepts with the current consumers and weight DAO,
+    /// so that a full batch fits in `BATCH_GAS_BUDGET` (at most `MAX_BATCH_OPS`)
+    fn max_batch_ops(&self) -> usize {
+        let budget = if self.funder.is_some() {
+            BATCH_GAS_BUDGET
+                .saturating_sub(TOP_UP_REQUEST_GAS)
+                .saturating_sub(PROMISE_OVERHEAD_GAS)
+        } else {
+            BATCH_GAS_BUDGET
+        };
+        let ops = budget
+            .as_gas()
+            .checked_div(self.store_op_gas().as_gas())
+            .unwrap_or(0);
+        std::cmp::min(usize::try_from(ops).unwrap_or(usize::MAX), MAX_BATCH_OPS)
+    }
+
+    /// Whether `account` may call `method`: a backend wallet, or an unexpired grant for it
+    fn can_call(&self, account: &AccountId, method: BackendMethod) -> bool {
+        self.is_backend(account)
+            || self.permissions.get(account).is_some_and(|grant| {
+                env::block_timestamp() < grant.expires_at && grant.methods.contains(&method)
+            })
+    }
+
+    /// Log an event in NEAR standard format and append it to the event log.
+    ///
+    /// Returns the payload hash, or `None` if the event could not be serialized.
+    fn emit_event<T: Serialize>(&mut self, event_name: &str, data: &T) -> Option<CryptoHash> {
+        let log = format_event(event_name, data)?;
+        env::log_str(&log);
+
+        let payload_hash = env::sha256_array(log.as_bytes());
+        let entry = EventLogEntry {
+            kind: event_name.to_string(),
+            payload_hash,
+            block_height: env::block_height(),
+        };
+        if self.event_log.len() < EVENT_LOG_CAPACITY {
+            self.event_log.push(entry);
+        } else {
+            // Full: overwrite the oldest entry
+            self.event_log.set(
+                (self.next_event_seq % u64::from(EVENT_LOG_CAPACITY)) as u32,
+                entry,
+            );
+        }
+        self.next_event_seq += 1;
+        Some(payload_hash)
+    }
+
+    /// Emit an admin event and append it to the audit hash chain
+    fn emit_admin_event<T: Serialize>(&mut self, event_name: &str, data: &T) {
+        let Some(payload_hash) = self.emit_event(event_name, data) else {
+            return;
+        };
+        let entry = AuditLogEntry {
+            kind: event_name.to_string(),
+            prev_hash: self.audit_head,
+            payload_hash,
+            block_height: env::block_height(),
+        };
+        self.audit_head = entry.entry_hash();
+        self.audit_log.push(entry);
+    }
+
+    /// Index of the registered terms version with `terms_hash`, if any
+    fn terms_version_of(&self, terms_hash: &str) -> Option<u32> {
+        self.terms_versions
+            .iter()
+            .position(|terms| terms.terms_hash == terms_hash)
+            .map(|index| index as u32)
+    }
+
+    /// Emit `config_updated` for a `ContractConfig` field changed by `by`
+    fn emit_config_updated(&mut self, field: &str, by: AccountId) {
+        self.emit_admin_event(
+            "config_updated",
+            &ConfigUpdatedEvent {
+                field: field.to_string(),
+                by,
+            },
+        );
+    }
+
+    /// Emit `low_balance_warning` if the available balance is below the threshold,
+    /// and ask the funder for a top-up.
+    ///
+    /// Called after writes that grow storage. Storage written by the current call is
+    /// only counted once it is flushed, so the estimate trails the write by one record.
+    /// `correlation_id` is that of the verification being written, if any.
+    fn check_balance(&mut self, correlation_id: Option<&str>) {
+        let estimate = CapacityEstimate::current(
+            self.low_balance_threshold,
+            u64::from(self.verifications.len()),
+        );
+        if estimate.available_balance < estimate.low_balance_threshold {
+            self.emit_event(
+                "low_balance_warning",
+                &LowBalanceWarningEvent {
+                    available_balance: estimate.available_balance,
+                    threshold: estimate.low_balance_threshold,
+                    estimated_verifications_remaining: estimate.estimated_verifications_remaining,
+                    correlation_id: correlation_id.map(str::to_string),
+                },
+            );
+            self.request_top_up(estimate.available_balance, correlation_id);
+        }
+    }
+
+    /// Ask the funder for enough to restore twice the threshold, at most once per
+    /// `TOP_UP_REQUEST_INTERVAL_NS`.
+    ///
+    /// The promise is detached: a missing or failing funder cannot revert the write.
+    fn request_top_up(&mut self, available_balance: U128, correlation_id: Option<&str>) {
+        let Some(funder) = self.funder.clone() else {
+            return;
+        };
+        let now = env::block_timestamp();
+        if self
+            .last_top_up_request_at
+            .is_some_and(|last| now.saturating_sub(last) < TOP_UP_REQUEST_INTERVAL_NS)
+        {
+            return;
+        }
+        self.last_top_up_request_at = Some(now);
+
+        let amount = U128(
+            self.low_balance_threshold
+                .as_yoctonear()
+                .saturating_mul(2)
+                .saturating_sub(available_balance.0),
+        );
+        ext_funder::ext(funder.clone())
+            .with_static_gas(TOP_UP_REQUEST_GAS)
+            .with_unused_gas_weight(0)
+            .request_top_up(amount)
+            .detach();
+        self.emit_event(
+            "top_up_requested",
+            &TopUpRequestedEvent {
+                funder,
+                amount,
+                correlation_id: correlation_id.map(str::to_string),
+            },
+        );
+    }
+
+    /// Remove an account's queued request and refund its deposit; false if none was queued
+    fn remove_verification_request(&mut self, account_id: &AccountId) -> bool {
+        if self.verification_queue.remove(account_id).is_none() {
+            return false;
+        }
+        Promise::new(account_id.clone())
+            .transfer(VERIFICATION_REQUEST_DEPOSIT)
+            .detach();
+        true
+    }
+
+    /// Delegate an account's citizen weight to the weight DAO, unless it already was.
+    ///
+    /// The delegation is recorded by `on_weight_delegated` once the DAO accepts it.
+    fn delegate_citizen_weight(&mut self, account_id: &AccountId, correlation_id: Option<String>) {
+        let Some(dao) = self.weight_dao.clone() else {
+            return;
+        };
+        if self.weight_delegations.get(account_id) == Some(&dao) {
+            return;
+        }
+        citizen_weight::delegate_weight(dao.clone(), account_id)
+            .then(
+                VersionedContract::ext(env::current_account_id())
+                    .with_static_gas(citizen_weight::DELEGATION_CALLBACK_GAS)
+                    .with_unused_gas_weight(0)
+                    .on_weight_delegated(account_id.clone(), dao, correlation_id),
+            )
+            .detach();
+    }
+
+    /// Push a status change to every registered consumer.
+    ///
+    /// Promises are detached so a failing or out-of-gas consumer cannot revert the write.
+    fn notify_consumers(
+        &self,
+        account_id: &AccountId,
+        status: VerificationStatus,
+        correlation_id: Option<String>,
+    ) {
+        for consumer in self.consumers.iter() {
+            ext_verification_consumer::ext(consumer.clone())
+                .with_static_gas(CONSUMER_NOTIFICATION_GAS)
+                .with_unused_gas_weight(0)
+                .on_verification_changed(account_id.clone(), status.clone(), correlation_id.clone())
+                .detach();
         }
     }
 }
@@ -189,10 +1462,38 @@ impl VersionedContract {
     /// Initialize contract with backend wallet address.
     #[init]
     pub fn new(backend_wallet: AccountId) -> Self {
-        VersionedContract::V1(ContractV1 {
+        VersionedContract::V2(ContractV2 {
             backend_wallet,
             verifications: IterableMap::new(StorageKey::Accounts),
             paused: false,
+            consumers: IterableSet::new(StorageKey::Consumers),
+            membership_log: Vector::new(StorageKey::MembershipLog),
+            membership_log_base: 0,
+            event_log: Vector::new(StorageKey::EventLog),
+            next_event_seq: 0,
+            backend_rotation: None,
+            allowed_app_ids: IterableSet::new(StorageKey::AllowedAppIds),
+            recipient_policy: RecipientPolicy::Contract,
+            permissions: IterableMap::new(StorageKey::Permissions),
+            audit_log: Vector::new(StorageKey::AuditLog),
+            audit_head: [0; 32],
+            verification_queue: IterableMap::new(StorageKey::VerificationQueue),
+            hash_only_storage: false,
+            terms_versions: Vector::new(StorageKey::TermsVersions),
+            consents: LookupMap::new(StorageKey::Consents),
+            mpc_public_key: None,
+            mpc_nonce: 0,
+            profiles: LookupMap::new(StorageKey::Profiles),
+            referrers: LookupMap::new(StorageKey::Referrers),
+            referral_counts: LookupMap::new(StorageKey::ReferralCounts),
+            low_balance_threshold: DEFAULT_LOW_BALANCE_THRESHOLD,
+            funder: None,
+            last_top_up_request_at: None,
+            linked_keys: LookupMap::new(StorageKey::LinkedKeys),
+            linked_key_nonces: LookupSet::new(StorageKey::LinkedKeyNonces),
+            last_heartbeat: None,
+            weight_dao: None,
+            weight_delegations: LookupMap::new(StorageKey::WeightDelegations),
         })
     }
 
@@ -205,157 +1506,1372 @@ impl VersionedContract {
     #[private]
     pub fn migrate() -> Self {
         // Read the existing state
-        let old_state: VersionedContract =
-            env::state_read().unwrap_or_else(|| env::panic_str("No state to migrate"));
+        let old_state: VersionedContract = env::state_read()
+            .unwrap_or_else(|| errors::panic_with_code(ErrorCode::NoState, "No state to migrate"));
+
+        // V1 -> V2 is also handled lazily by `contract_mut()`; migrating eagerly here
+        // just avoids paying the upgrade cost on the first backend write.
+        match old_state {
+            VersionedContract::V1(v1) => VersionedContract::V2(ContractV2::from(v1)),
+            VersionedContract::V2(v2) => VersionedContract::V2(v2),
+        }
+    }
+
+    /// Update the backend wallet address (only callable by backend wallet)
+    #[payable]
+    pub fn update_backend_wallet(&mut self, new_backend_wallet: AccountId) {
+        assert_one_yocto();
+
+        let contract = self.contract_mut();
+        ensure!(
+            env::predecessor_account_id() == contract.backend_wallet,
+            ErrorCode::Unauthorized,
+            "Only backend wallet can update backend wallet"
+        );
+        ensure!(
+            contract.backend_rotation.is_none(),
+            ErrorCode::RotationInProgress,
+            "Complete the pending rotation before updating the backend wallet"
+        );
+        let old_wallet = contract.backend_wallet.clone();
+        contract.backend_wallet = new_backend_wallet.clone();
+
+        contract.emit_admin_event(
+            "backend_wallet_updated",
+            &BackendWalletUpdatedEvent {
+                old_wallet: old_wallet.clone(),
+                new_wallet: new_backend_wallet,
+            },
+        );
+        contract.emit_config_updated("backend_wallet", old_wallet);
+    }
+
+    /// Begin rotating to `new_wallet` (only callable by backend wallet)
+    ///
+    /// During the `grace_blocks` grace period both wallets can make operational writes,
+    /// but only the current wallet can change configuration or finish the rotation.
+    /// Afterwards only the new wallet is accepted.
+    #[payable]
+    pub fn begin_rotation(&mut self, new_wallet: AccountId, grace_blocks: u64) {
+        assert_one_yocto();
+
+        let contract = self.contract_mut();
+        ensure!(
+            env::predecessor_account_id() == contract.backend_wallet,
+            ErrorCode::Unauthorized,
+            "Only backend wallet can begin rotation"
+        );
+        ensure!(
+            contract.backend_rotation.is_none(),
+            ErrorCode::RotationInProgress,
+            "Backend wallet rotation already in progress"
+        );
+
+        let expires_at_block = env::block_height().saturating_add(grace_blocks);
+        contract.backend_rotation = Some(BackendRotation {
+            new_wallet: new_wallet.clone(),
+            expires_at_block,
+        });
+
+        let old_wallet = contract.backend_wallet.clone();
+        contract.emit_admin_event(
+            "backend_rotation_started",
+            &BackendRotationStartedEvent {
+                old_wallet,
+                new_wallet,
+                expires_at_block,
+            },
+        );
+    }
+
+    /// Cancel the pending rotation, withdrawing the new wallet's authorization
+    /// (only callable by the current backend wallet during the grace period)
+    ///
+    /// The new wallet cannot cancel, so a compromised or mistyped new wallet can be
+    /// revoked before it takes over.
+    #[payable]
+    pub fn cancel_rotation(&mut self) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        let Some(rotation) = contract.backend_rotation.clone() else {
+            errors::panic_with_code(
+                ErrorCode::NoRotation,
+                "No backend wallet rotation in progress",
+            )
+        };
+        ensure!(
+            caller == contract.backend_wallet && env::block_height() <= rotation.expires_at_block,
+            ErrorCode::Unauthorized,
+            "Only the current backend wallet can cancel rotation during the grace period"
+        );
+        contract.backend_rotation = None;
+
+        contract.emit_admin_event(
+            "backend_rotation_cancelled",
+            &BackendRotationCancelledEvent {
+                backend_wallet: caller,
+                new_wallet: rotation.new_wallet,
+            },
+        );
+    }
+
+    /// Finish the pending rotation, leaving the new wallet as the sole backend wallet
+    /// (callable by the current wallet during the grace period, or the new wallet after it)
+    #[payable]
+    pub fn complete_rotation(&mut self) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can complete rotation"
+        );
+        let Some(rotation) = contract.backend_rotation.take() else {
+            errors::panic_with_code(
+                ErrorCode::NoRotation,
+                "No backend wallet rotation in progress",
+            )
+        };
+
+        let old_wallet =
+            std::mem::replace(&mut contract.backend_wallet, rotation.new_wallet.clone());
+        contract.emit_admin_event(
+            "backend_wallet_updated",
+            &BackendWalletUpdatedEvent {
+                old_wallet,
+                new_wallet: rotation.new_wallet,
+            },
+        );
+        contract.emit_config_updated("backend_wallet", caller);
+    }
+
+    /// Grant `account` permission to call `methods` until `expires_at` (nanoseconds),
+    /// replacing any existing grant (only callable by backend wallet)
+    #[payable]
+    pub fn grant_permission(
+        &mut self,
+        account: AccountId,
+        methods: Vec<BackendMethod>,
+        expires_at: u64,
+    ) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can grant permissions"
+        );
+        ensure!(
+            !methods.is_empty(),
+            ErrorCode::InvalidPermission,
+            "Grant must include at least one method"
+        );
+        ensure!(
+            expires_at > env::block_timestamp(),
+            ErrorCode::InvalidPermission,
+            "Grant expiry must be in the future"
+        );
+
+        let methods = methods.into_iter().fold(Vec::new(), |mut unique, method| {
+            if !unique.contains(&method) {
+                unique.push(method);
+            }
+            unique
+        });
+        contract.permissions.insert(
+            account.clone(),
+            PermissionGrant {
+                methods: methods.clone(),
+                expires_at,
+            },
+        );
+
+        contract.emit_admin_event(
+            "permission_granted",
+            &PermissionGrantedEvent {
+                account_id: account,
+                methods,
+                expires_at,
+                by: caller,
+            },
+        );
+    }
+
+    /// Revoke a scoped permission before it expires (only callable by backend wallet)
+    #[payable]
+    pub fn revoke_permission(&mut self, account: AccountId) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
 
-        // Currently no migration needed - just return the existing state.
-        // When adding V2, this would transform V1 -> V2:
-        //
-        // match old_state {
-        //     VersionedContract::V1(v1) => {
-        //         VersionedContract::V2(ContractV2 {
-        //             backend_wallet: v1.backend_wallet,
-        //             nullifiers: v1.nullifiers,
-        //             verifications: v1.verifications,
-        //             paused: v1.paused,
-        //         })
-        //     }
+        let contract = self.contract_mut();
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can revoke permissions"
+        );
+        ensure!(
+            contract.permissions.remove(&account).is_some(),
+            ErrorCode::PermissionNotFound,
+            "No permission granted to {}",
+            account
+        );
+
+        contract.emit_admin_event(
+            "permission_revoked",
+            &PermissionRevokedEvent {
+                account_id: account,
+                by: caller,
+            },
+        );
+    }
+
+    /// Pause the contract (backend wallet or `pause` grantee)
+    /// When paused, no new verifications can be stored
+    #[payable]
+    pub fn pause(&mut self) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.can_call(&caller, BackendMethod::Pause),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can pause contract"
+        );
+        ensure!(
+            !contract.paused,
+            ErrorCode::AlreadyPaused,
+            "Contract is already paused"
+        );
+        contract.paused = true;
+
+        contract.emit_admin_event("contract_paused", &ContractPausedEvent { by: caller });
+    }
+
+    /// Unpause the contract (backend wallet or `unpause` grantee)
+    #[payable]
+    pub fn unpause(&mut self) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.can_call(&caller, BackendMethod::Unpause),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can unpause contract"
+        );
+        ensure!(
+            contract.paused,
+            ErrorCode::NotPaused,
+            "Contract is not paused"
+        );
+        contract.paused = false;
+
+        contract.emit_admin_event("contract_unpaused", &ContractUnpausedEvent { by: caller });
+    }
+
+    /// Record that the backend is operating (only callable by backend wallet)
+    ///
+    /// Emits no event, so it can be sent frequently without filling the event log.
+    #[payable]
+    pub fn heartbeat(&mut self) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.is_backend(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can send heartbeats"
+        );
+        contract.last_heartbeat = Some(BackendHeartbeat {
+            block_height: env::block_height(),
+            timestamp: env::block_timestamp(),
+        });
+    }
+
+    /// Queue a verification request for the caller (any account)
+    ///
+    /// Leaves a public trace of the off-chain request; the backend removes it when the
+    /// verification is stored, consumed, or expired. Requires a deposit of 0.01 NEAR,
+    /// refunded to the caller when the entry is removed.
+    #[payable]
+    pub fn request_verification(&mut self) {
+        ensure!(
+            env::attached_deposit() == VERIFICATION_REQUEST_DEPOSIT,
+            ErrorCode::RequestDepositRequired,
+            "Requires attached deposit of exactly {}",
+            VERIFICATION_REQUEST_DEPOSIT
+        );
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            !contract.paused,
+            ErrorCode::Paused,
+            "Contract is paused - no new verification requests allowed"
+        );
+        ensure!(
+            !contract.verifications.contains_key(&caller),
+            ErrorCode::AlreadyVerified,
+            "NEAR account already verified"
+        );
+        ensure!(
+            !contract.verification_queue.contains_key(&caller),
+            ErrorCode::AlreadyRequested,
+            "Verification already requested for {}",
+            caller
+        );
+        ensure!(
+            contract.verification_queue.len() < MAX_VERIFICATION_QUEUE_LEN,
+            ErrorCode::QueueFull,
+            "Verification queue is full (maximum {} requests)",
+            MAX_VERIFICATION_QUEUE_LEN
+        );
+
+        let requested_at = env::block_timestamp();
+        contract
+            .verification_queue
+            .insert(caller.clone(), requested_at);
+
+        contract.emit_event(
+            "verification_requested",
+            &VerificationRequestedEvent {
+                account_id: caller,
+                requested_at,
+            },
+        );
+        contract.check_balance(None);
+    }
 
-        //     VersionedContract::V2(v2) => VersionedContract::V2(v2),
-        // }
+    /// Delete the caller's stored `user_context_data` (verified accounts only)
+    ///
+    /// Keeps `verified_at` and `user_context_hash`, so the account stays verified and the
+    /// deleted data can still be matched against the hash off-chain.
+    #[payable]
+    pub fn prune_my_proof_data(&mut self) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        let Some(record) = contract.verifications.get(&caller) else {
+            errors::panic_with_code(ErrorCode::NotVerified, "NEAR account is not verified")
+        };
+        let mut record = record.as_current();
+        ensure!(
+            record.context_storage == ContextStorage::Full,
+            ErrorCode::NothingToPrune,
+            "Record holds no context data to prune"
+        );
+
+        record.user_context_data = String::new();
+        record.context_storage = ContextStorage::Pruned;
+        contract
+            .verifications
+            .insert(caller.clone(), VersionedVerification::from(record));
+
+        contract.emit_event(
+            "proof_data_pruned",
+            &ProofDataPrunedEvent { account_id: caller },
+        );
+    }
+
+    /// Set the caller's public member profile (verified accounts only)
+    ///
+    /// Replaces any existing profile. Fields are bounded so profiles stay small.
+    #[payable]
+    pub fn set_profile(&mut self, profile: MemberProfile) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.verifications.contains_key(&caller),
+            ErrorCode::NotVerified,
+            "NEAR account is not verified"
+        );
+        if let Some(handle_hash) = &profile.handle_hash {
+            ensure!(
+                handle_hash.len() == 64
+                    && handle_hash
+                        .bytes()
+                        .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b)),
+                ErrorCode::InvalidProfile,
+                "Handle hash must be 64 lowercase hex characters"
+            );
+        }
+        if let Some(avatar_cid) = &profile.avatar_cid {
+            ensure!(
+                avatar_cid.len() <= MAX_PROFILE_AVATAR_CID_LEN,
+                ErrorCode::InputTooLong,
+                "Avatar CID exceeds maximum length of {}",
+                MAX_PROFILE_AVATAR_CID_LEN
+            );
+        }
+        ensure!(
+            profile.links.len() <= MAX_PROFILE_LINKS,
+            ErrorCode::InvalidProfile,
+            "Profile may have at most {} links",
+            MAX_PROFILE_LINKS
+        );
+        ensure!(
+            profile
+                .links
+                .iter()
+                .all(|link| link.len() <= MAX_PROFILE_LINK_LEN),
+            ErrorCode::InputTooLong,
+            "Profile link exceeds maximum length of {}",
+            MAX_PROFILE_LINK_LEN
+        );
+
+        contract.profiles.insert(caller.clone(), profile);
+        contract.emit_event(
+            "profile_updated",
+            &ProfileUpdatedEvent {
+                account_id: caller,
+                cleared: false,
+            },
+        );
+        contract.check_balance(None);
+    }
+
+    /// Remove the caller's member profile
+    #[payable]
+    pub fn clear_profile(&mut self) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        if contract.profiles.remove(&caller).is_some() {
+            contract.emit_event(
+                "profile_updated",
+                &ProfileUpdatedEvent {
+                    account_id: caller,
+                    cleared: true,
+                },
+            );
+        }
+    }
+
+    /// Link a new NEP-413 public key to the caller's verification (verified accounts only)
+    ///
+    /// `signature_data` must be signed by the new key over `linked_key_challenge(caller)`
+    /// with a nonce never used for a rotation before, naming this contract as recipient
+    /// under the current recipient policy. `account_id` is not signed, so the challenge is
+    /// what binds the signature to the caller.
+    #[payable]
+    pub fn rotate_linked_key(&mut self, signature_data: NearSignatureData) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.verifications.contains_key(&caller),
+            ErrorCode::NotVerified,
+            "NEAR account is not verified"
+        );
+        ensure!(
+            signature_data.account_id == caller,
+            ErrorCode::SignatureAccountMismatch,
+            "Signature account ID must match the caller"
+        );
+        ensure!(
+            signature_data.challenge == linked_key_challenge(&caller),
+            ErrorCode::InvalidChallenge,
+            "Signed challenge must be \"{}\"",
+            linked_key_challenge(&caller)
+        );
+        ensure!(
+            !contract.linked_key_nonces.contains(&signature_data.nonce.0),
+            ErrorCode::NonceReused,
+            "Nonce has already been used to rotate a linked key"
+        );
+        ensure!(
+            contract
+                .recipient_policy
+                .allows(&signature_data.recipient, &signature_data.account_id),
+            ErrorCode::SignatureRecipientMismatch,
+            "Signature recipient must match {}",
+            contract.recipient_policy.expected_recipient()
+        );
+        Self::verify_near_signature(&signature_data);
+
+        contract
+            .linked_key_nonces
+            .insert(signature_data.nonce.0.clone());
+        contract
+            .linked_keys
+            .insert(caller.clone(), signature_data.public_key.clone());
+        contract.emit_event(
+            "linked_key_rotated",
+            &LinkedKeyRotatedEvent {
+                account_id: caller,
+                public_key: signature_data.public_key,
+            },
+        );
+    }
+
+    /// Remove a queued request the backend has picked up
+    /// (backend wallet or `store_verification` grantee)
+    #[payable]
+    pub fn consume_verification_request(&mut self, account_id: AccountId) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.can_call(&caller, BackendMethod::StoreVerification),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can consume verification requests"
+        );
+        ensure!(
+            contract.remove_verification_request(&account_id),
+            ErrorCode::RequestNotFound,
+            "No verification request queued for {}",
+            account_id
+        );
+
+        contract.emit_event(
+            "verification_requests_removed",
+            &VerificationRequestsRemovedEvent {
+                account_ids: vec![account_id],
+                reason: "consumed".to_string(),
+                by: caller,
+            },
+        );
+    }
+
+    /// Remove up to `limit` (max 100) requests queued before `older_than` (nanoseconds)
+    /// (backend wallet or `store_verification` grantee)
+    ///
+    /// Returns the number removed; call again until it returns 0.
+    #[payable]
+    pub fn expire_verification_requests(&mut self, older_than: u64, limit: u32) -> u32 {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.can_call(&caller, BackendMethod::StoreVerification),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can expire verification requests"
+        );
+
+        let expired: Vec<AccountId> = contract
+            .verification_queue
+            .iter()
+            .filter(|(_, requested_at)| **requested_at < older_than)
+            .take(std::cmp::min(limit, MAX_PAGE_SIZE) as usize)
+            .map(|(account_id, _)| account_id.clone())
+            .collect();
+        for account_id in &expired {
+            contract.remove_verification_request(account_id);
+        }
+
+        let count = expired.len() as u32;
+        if count > 0 {
+            contract.emit_event(
+                "verification_requests_removed",
+                &VerificationRequestsRemovedEvent {
+                    account_ids: expired,
+                    reason: "expired".to_string(),
+                    by: caller,
+                },
+            );
+        }
+        count
+    }
+
+    /// Store a verified account with NEAR signature verification
+    /// (backend wallet or `store_verification` grantee)
+    ///
+    /// If `callback` is `(contract, method)`,
+    /// `contract.method({"account_id": ..., "correlation_id": ...})` is called
+    /// fire-and-forget with 5 TGas once the record is stored.
+    #[payable]
+    pub fn store_verification(
+        &mut self,
+        near_account_id: AccountId,
+        signature_data: NearSignatureData,
+        user_context_data: String,
+        callback: Option<(AccountId, String)>,
+    ) {
+        assert_one_yocto();
+        self.apply_store_verification(
+            near_account_id,
+            signature_data,
+            user_context_data,
+            callback,
+            false,
+        );
+    }
+
+    /// Store a verification authorized by the configured MPC key rather than the caller
+    /// (any account may relay)
+    ///
+    /// `mpc_signature` is 65 bytes (`r || s || v`) over `sha256(borsh(MpcStorePayload))`.
+    /// All `store_verification` checks apply except the caller check.
+    #[payable]
+    pub fn store_verification_mpc(
+        &mut self,
+        near_account_id: AccountId,
+        signature_data: NearSignatureData,
+        user_context_data: String,
+        mpc_nonce: u64,
+        mpc_signature: Base64VecU8,
+    ) {
+        assert_one_yocto();
+
+        let contract = self.contract_mut();
+        let Some(public_key) = contract.mpc_public_key.as_deref() else {
+            errors::panic_with_code(ErrorCode::MpcNotConfigured, "No MPC public key configured")
+        };
+        ensure!(
+            mpc_nonce > contract.mpc_nonce,
+            ErrorCode::StaleMpcNonce,
+            "MPC nonce must be greater than {}",
+            contract.mpc_nonce
+        );
+
+        let payload = MpcStorePayload {
+            contract_id: env::current_account_id(),
+            nonce: mpc_nonce,
+            near_account_id: near_account_id.clone(),
+            user_signature: signature_data.signature.0.clone(),
+            user_context_hash: env::sha256_array(user_context_data.as_bytes()),
+            referred_by: signature_data.referred_by.clone(),
+        };
+        let Ok(payload_bytes) = near_sdk::borsh::to_vec(&payload) else {
+            errors::panic_with_code(
+                ErrorCode::PayloadSerialization,
+                "Failed to serialize MPC payload",
+            )
+        };
+        let digest = env::sha256_array(&payload_bytes);
+        let recovered = match (mpc_signature.0.get(..64), mpc_signature.0.get(64)) {
+            (Some(rs), Some(&v)) if mpc_signature.0.len() == 65 => {
+                env::ecrecover(&digest, rs, v, true)
+            }
+            _ => None,
+        };
+        ensure!(
+            recovered.is_some_and(|key| key.as_slice() == public_key),
+            ErrorCode::InvalidMpcSignature,
+            "MPC signature does not match the configured key"
+        );
+        contract.mpc_nonce = mpc_nonce;
+
+        self.apply_store_verification(
+            near_account_id,
+            signature_data,
+            user_context_data,
+            None,
+            true,
+        );
+    }
+
+    /// Apply several backend writes in one transaction
+    /// (backend wallet or grantee of every method in the batch)
+    ///
+    /// All-or-nothing: if any operation fails the whole batch reverts. Returns one
+    /// result per operation, in order, and emits `batch_executed` with the same list.
+    #[payable]
+    pub fn execute_batch(&mut self, ops: Vec<BackendOp>) -> Vec<BackendOpResult> {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        ensure!(
+            self.contract_mut()
+                .can_call(&caller, BackendMethod::StoreVerification),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can execute batches"
+        );
+        let max_ops = self.contract_mut().max_batch_ops();
+        ensure!(
+            ops.len() <= max_ops,
+            ErrorCode::BatchTooLarge,
+            "Batch size exceeds maximum of {} operations for the current consumers",
+            max_ops
+        );
+
+        let mut results = Vec::with_capacity(ops.len());
+        for (index, op) in (0u32..).zip(ops) {
+            match op {
+                BackendOp::Store {
+                    near_account_id,
+                    signature_data,
+                    user_context_data,
+                    callback,
+                } => {
+                    let correlation_id = signature_data.correlation_id();
+                    self.apply_store_verification(
+                        near_account_id.clone(),
+                        signature_data,
+                        user_context_data,
+                        callback,
+                        false,
+                    );
+                    results.push(BackendOpResult {
+                        index,
+                        op: "store".to_string(),
+                        near_account_id,
+                        correlation_id: Some(correlation_id),
+                    });
+                }
+            }
+        }
+
+        self.contract_mut().emit_event(
+            "batch_executed",
+            &BatchExecutedEvent {
+                by: caller,
+                results: results.clone(),
+            },
+        );
+        results
+    }
+
+    /// Validate and store one verification; shared by the store methods and `execute_batch`.
+    ///
+    /// `mpc_authorized` skips the caller check for writes already authorized by an MPC signature.
+    fn apply_store_verification(
+        &mut self,
+        near_account_id: AccountId,
+        signature_data: NearSignatureData,
+        user_context_data: String,
+        callback: Option<(AccountId, String)>,
+        mpc_authorized: bool,
+    ) {
+        let contract = self.contract_mut();
+
+        // Check if contract is paused
+        ensure!(
+            !contract.paused,
+            ErrorCode::Paused,
+            "Contract is paused - no new verifications allowed"
+        );
+
+        // Input length validation
+        ensure!(
+            user_context_data.len() <= MAX_USER_CONTEXT_DATA_LEN,
+            ErrorCode::InputTooLong,
+            "User context data exceeds maximum length of {}",
+            MAX_USER_CONTEXT_DATA_LEN
+        );
+        if let Some((_, method)) = &callback {
+            ensure!(
+                method.len() <= MAX_CALLBACK_METHOD_LEN,
+                ErrorCode::InputTooLong,
+                "Callback method name exceeds maximum length of {}",
+                MAX_CALLBACK_METHOD_LEN
+            );
+        }
+
+        // Access control: only backend wallet can write
+        ensure!(
+            mpc_authorized
+                || contract.can_call(
+                    &env::predecessor_account_id(),
+                    BackendMethod::StoreVerification
+                ),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can store verifications"
+        );
+
+        // Verify signature data matches the account being verified
+        ensure!(
+            signature_data.account_id == near_account_id,
+            ErrorCode::SignatureAccountMismatch,
+            "Signature account ID must match near_account_id"
+        );
+
+        ensure!(
+            contract
+                .recipient_policy
+                .allows(&signature_data.recipient, &signature_data.account_id),
+            ErrorCode::SignatureRecipientMismatch,
+            "Signature recipient must match {}",
+            contract.recipient_policy.expected_recipient()
+        );
+
+        // App binding: required once an allowlist is configured
+        match &signature_data.app_id {
+            Some(app_id) => ensure!(
+                contract.allowed_app_ids.is_empty() || contract.allowed_app_ids.contains(app_id),
+                ErrorCode::AppIdNotAllowed,
+                "App ID {} is not allowed",
+                app_id
+            ),
+            None => ensure!(
+                contract.allowed_app_ids.is_empty(),
+                ErrorCode::AppIdNotAllowed,
+                "Signature must include an allowed app ID"
+            ),
+        }
+
+        // Consent: required once a terms version is registered
+        let terms_version = match &signature_data.terms_hash {
+            Some(terms_hash) => {
+                let Some(version) = contract.terms_version_of(terms_hash) else {
+                    errors::panic_with_code(
+                        ErrorCode::TermsNotAccepted,
+                        &format!("Terms version {} is not registered", terms_hash),
+                    )
+                };
+                ensure!(
+                    signature_data.challenge.contains(terms_hash.as_str()),
+                    ErrorCode::TermsNotAccepted,
+                    "Signed challenge must reference terms hash {}",
+                    terms_hash
+                );
+                Some(version)
+            }
+            None => {
+                ensure!(
+                    contract.terms_versions.is_empty(),
+                    ErrorCode::TermsNotAccepted,
+                    "Signature must reference a registered terms version"
+                );
+                None
+            }
+        };
+
+        // Referral: the referrer must be a verified citizen under the per-account cap
+        let referral = match signature_data.referred_by.clone() {
+            Some(referrer) => {
+                ensure!(
+                    contract.verifications.contains_key(&referrer),
+                    ErrorCode::InvalidReferrer,
+                    "Referrer {} is not verified",
+                    referrer
+                );
+                let count = contract
+                    .referral_counts
+                    .get(&referrer)
+                    .copied()
+                    .unwrap_or_default();
+                ensure!(
+                    count < MAX_REFERRALS_PER_ACCOUNT,
+                    ErrorCode::ReferralLimit,
+                    "Referrer {} has reached the limit of {} referrals",
+                    referrer,
+                    MAX_REFERRALS_PER_ACCOUNT
+                );
+                Some((referrer, count))
+            }
+            None => None,
+        };
+
+        // Verify the NEAR signature
+        Self::verify_near_signature(&signature_data);
+
+        // Prevent re-verification of accounts
+        ensure!(
+            !contract.verifications.contains_key(&near_account_id),
+            ErrorCode::AlreadyVerified,
+            "NEAR account already verified"
+        );
+
+        // Create verification record (always use current version)
+        let user_context_hash = Verification::hash_context(&user_context_data);
+        let (user_context_data, context_storage) = if contract.hash_only_storage {
+            (String::new(), ContextStorage::HashOnly)
+        } else {
+            (user_context_data, ContextStorage::Full)
+        };
+        let verification = Verification {
+            near_account_id: near_account_id.clone(),
+            verified_at: env::block_timestamp(),
+            user_context_data,
+            user_context_hash,
+            context_storage,
+        };
+
+        // Store verification
+        contract.verifications.insert(
+            near_account_id.clone(),
+            VersionedVerification::from(verification),
+        );
+        contract.remove_verification_request(&near_account_id);
+        contract
+            .linked_keys
+            .insert(near_account_id.clone(), signature_data.public_key.clone());
+        if let Some(terms_version) = terms_version {
+            contract.consents.insert(
+                near_account_id.clone(),
+                ConsentRecord {
+                    terms_version,
+                    consented_at: env::block_timestamp(),
+                },
+            );
+        }
+        contract.membership_log.push(MembershipLogEntry {
+            account_id: near_account_id.clone(),
+            change: MembershipChange::Added,
+            timestamp: env::block_timestamp(),
+        });
+
+        // Emit event
+        let correlation_id = signature_data.correlation_id();
+        contract.emit_event(
+            "verification_stored",
+            &VerificationStoredEvent {
+                near_account_id: near_account_id.clone(),
+                correlation_id: correlation_id.clone(),
+            },
+        );
+        if let Some((referrer, count)) = referral {
+            contract
+                .referrers
+                .insert(near_account_id.clone(), referrer.clone());
+            contract.referral_counts.insert(referrer.clone(), count + 1);
+            contract.emit_event(
+                "referral_recorded",
+                &ReferralRecordedEvent {
+                    account_id: near_account_id.clone(),
+                    referred_by: referrer,
+                    correlation_id: correlation_id.clone(),
+                },
+            );
+        }
+        contract.delegate_citizen_weight(&near_account_id, Some(correlation_id.clone()));
+        contract.check_balance(Some(&correlation_id));
+
+        contract.notify_consumers(
+            &near_account_id,
+            VerificationStatus::Verified,
+            Some(correlation_id.clone()),
+        );
+
+        if let Some((callback_contract, method)) = callback {
+            let args = near_sdk::serde_json::json!({
+                "account_id": near_account_id,
+                "correlation_id": correlation_id,
+            });
+            Promise::new(callback_contract)
+                .function_call_weight(
+                    method,
+                    args.to_string().into_bytes(),
+                    NearToken::from_yoctonear(0),
+                    COMPLETION_CALLBACK_GAS,
+                    GasWeight(0),
+                )
+                .detach();
+        }
+    }
+
+    /// Register a contract to receive `on_verification_changed` notifications
+    /// (backend wallet or `register_consumer` grantee)
+    #[payable]
+    pub fn register_consumer(&mut self, contract_id: AccountId) {
+        assert_one_yocto();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.can_call(
+                &env::predecessor_account_id(),
+                BackendMethod::RegisterConsumer
+            ),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can register consumers"
+        );
+        ensure!(
+            contract.consumers.len() < MAX_CONSUMERS,
+            ErrorCode::ConsumerLimit,
+            "Consumer limit of {} reached",
+            MAX_CONSUMERS
+        );
+        ensure!(
+            contract.consumers.insert(contract_id.clone()),
+            ErrorCode::ConsumerAlreadyRegistered,
+            "Consumer already registered"
+        );
+
+        contract.emit_admin_event(
+            "consumer_registered",
+            &ConsumerRegisteredEvent { contract_id },
+        );
+    }
+
+    /// Remove a contract from the consumer registry
+    /// (backend wallet or `unregister_consumer` grantee)
+    #[payable]
+    pub fn unregister_consumer(&mut self, contract_id: AccountId) {
+        assert_one_yocto();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.can_call(
+                &env::predecessor_account_id(),
+                BackendMethod::UnregisterConsumer
+            ),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can unregister consumers"
+        );
+        ensure!(
+            contract.consumers.remove(&contract_id),
+            ErrorCode::ConsumerNotRegistered,
+            "Consumer not registered"
+        );
+
+        contract.emit_admin_event(
+            "consumer_unregistered",
+            &ConsumerUnregisteredEvent { contract_id },
+        );
+    }
+
+    /// Set which NEP-413 recipient signatures must name (only callable by backend wallet)
+    #[payable]
+    pub fn set_recipient_policy(&mut self, policy: RecipientPolicy) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can set recipient policy"
+        );
+        contract.recipient_policy = policy;
+
+        contract.emit_config_updated("recipient_policy", caller);
+    }
+
+    /// Set or clear the MPC key that authorizes `store_verification_mpc`
+    /// (only callable by backend wallet)
+    ///
+    /// `public_key` is the 64-byte uncompressed secp256k1 key without the `0x04` prefix.
+    #[payable]
+    pub fn set_mpc_public_key(&mut self, public_key: Option<Base64VecU8>) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can set the MPC public key"
+        );
+        if let Some(key) = &public_key {
+            ensure!(
+                key.0.len() == 64,
+                ErrorCode::InvalidMpcKey,
+                "MPC public key must be 64 bytes, got {}",
+                key.0.len()
+            );
+        }
+        contract.mpc_public_key = public_key.map(|key| key.0);
+
+        contract.emit_config_updated("mpc_public_key", caller);
+    }
+
+    /// Store only the hash of `user_context_data` in new records (only callable by backend wallet)
+    ///
+    /// Existing records are unchanged; each record's `context_storage` reports its mode.
+    #[payable]
+    pub fn set_hash_only_storage(&mut self, enabled: bool) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can set storage mode"
+        );
+        contract.hash_only_storage = enabled;
+
+        contract.emit_config_updated("hash_only_storage", caller);
+    }
+
+    /// Set the available balance (yoctoNEAR) below which writes emit `low_balance_warning`
+    /// (only callable by backend wallet)
+    #[payable]
+    pub fn set_low_balance_threshold(&mut self, threshold: U128) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can set low balance threshold"
+        );
+        contract.low_balance_threshold = NearToken::from_yoctonear(threshold.0);
 
-        old_state
+        contract.emit_config_updated("low_balance_threshold", caller);
     }
 
-    /// Update the backend wallet address (only callable by backend wallet)
+    /// Set or clear the account asked via `request_top_up` when the balance is low
+    /// (only callable by backend wallet)
     #[payable]
-    pub fn update_backend_wallet(&mut self, new_backend_wallet: AccountId) {
+    pub fn set_funder(&mut self, funder: Option<AccountId>) {
         assert_one_yocto();
+        let caller = env::predecessor_account_id();
 
         let contract = self.contract_mut();
-        assert_eq!(
-            env::predecessor_account_id(),
-            contract.backend_wallet,
-            "Only backend wallet can update backend wallet"
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can set funder"
         );
-        let old_wallet = contract.backend_wallet.clone();
-        contract.backend_wallet = new_backend_wallet.clone();
+        contract.funder = funder;
 
-        emit_event(
-            "backend_wallet_updated",
-            &BackendWalletUpdatedEvent {
-                old_wallet,
-                new_wallet: new_backend_wallet,
-            },
-        );
+        contract.emit_config_updated("funder", caller);
     }
 
-    /// Pause the contract (only callable by backend wallet)
-    /// When paused, no new verifications can be stored
+    /// Set or clear the SputnikDAO that verified accounts delegate citizen weight to
+    /// (only callable by backend wallet)
+    ///
+    /// The DAO must already name this contract as its staking contract. New
+    /// verifications delegate automatically; use `sync_citizen_weight` for existing ones.
     #[payable]
-    pub fn pause(&mut self) {
+    pub fn set_weight_dao(&mut self, dao: Option<AccountId>) {
         assert_one_yocto();
         let caller = env::predecessor_account_id();
 
         let contract = self.contract_mut();
-        assert_eq!(
-            caller, contract.backend_wallet,
-            "Only backend wallet can pause contract"
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can set weight DAO"
         );
-        assert!(!contract.paused, "Contract is already paused");
-        contract.paused = true;
+        contract.weight_dao = dao;
 
-        emit_event("contract_paused", &ContractPausedEvent { by: caller });
+        contract.emit_config_updated("weight_dao", caller);
     }
 
-    /// Unpause the contract (only callable by backend wallet)
+    /// Delegate citizen weight for accounts verified before the weight DAO was set, or
+    /// whose delegation the DAO rejected (only callable by backend wallet)
+    ///
+    /// Accounts already delegated to the current DAO are skipped.
     #[payable]
-    pub fn unpause(&mut self) {
+    pub fn sync_citizen_weight(&mut self, account_ids: Vec<AccountId>) {
         assert_one_yocto();
-        let caller = env::predecessor_account_id();
 
         let contract = self.contract_mut();
-        assert_eq!(
-            caller, contract.backend_wallet,
-            "Only backend wallet can unpause contract"
+        ensure!(
+            contract.is_backend(&env::predecessor_account_id()),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can sync citizen weight"
         );
-        assert!(contract.paused, "Contract is not paused");
-        contract.paused = false;
-
-        emit_event("contract_unpaused", &ContractUnpausedEvent { by: caller });
+        ensure!(
+            contract.weight_dao.is_some(),
+            ErrorCode::WeightDaoNotConfigured,
+            "Weight DAO is not set"
+        );
+        ensure!(
+            account_ids.len() <= MAX_BATCH_OPS,
+            ErrorCode::BatchTooLarge,
+            "Batch size exceeds maximum of {} accounts",
+            MAX_BATCH_OPS
+        );
+        for account_id in &account_ids {
+            ensure!(
+                contract.verifications.contains_key(account_id),
+                ErrorCode::NotVerified,
+                "NEAR account {} is not verified",
+                account_id
+            );
+            contract.delegate_citizen_weight(account_id, None);
+        }
     }
 
-    /// Store a verified account with NEAR signature verification (only callable by backend wallet)
-    #[payable]
-    pub fn store_verification(
+    /// Record a weight delegation once the DAO accepted it (callback of the delegation batch)
+    ///
+    /// A rejected delegation stays unrecorded, so `sync_citizen_weight` can retry it.
+    #[private]
+    pub fn on_weight_delegated(
         &mut self,
-        near_account_id: AccountId,
-        signature_data: NearSignatureData,
-        user_context_data: String,
+        account_id: AccountId,
+        dao: AccountId,
+        correlation_id: Option<String>,
     ) {
+        if !near_sdk::is_promise_success() {
+            return;
+        }
+        let contract = self.contract_mut();
+        contract
+            .weight_delegations
+            .insert(account_id.clone(), dao.clone());
+        contract.emit_event(
+            "citizen_weight_delegated",
+            &CitizenWeightDelegatedEvent {
+                account_id,
+                dao,
+                correlation_id,
+            },
+        );
+    }
+
+    /// Register a new terms version by its SHA-256 hex, making it current
+    /// (only callable by backend wallet)
+    ///
+    /// From then on every stored signature must reference a registered terms version.
+    #[payable]
+    pub fn register_terms_version(&mut self, terms_hash: String) {
         assert_one_yocto();
+        let caller = env::predecessor_account_id();
 
         let contract = self.contract_mut();
-
-        // Check if contract is paused
-        assert!(
-            !contract.paused,
-            "Contract is paused - no new verifications allowed"
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can register terms versions"
         );
-
-        // Input length validation
-        assert!(
-            user_context_data.len() <= MAX_USER_CONTEXT_DATA_LEN,
-            "User context data exceeds maximum length of {}",
-            MAX_USER_CONTEXT_DATA_LEN
+        ensure!(
+            terms_hash.len() == 64
+                && terms_hash
+                    .bytes()
+                    .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b)),
+            ErrorCode::InvalidTermsHash,
+            "Terms hash must be 64 lowercase hex characters"
         );
-
-        // Access control: only backend wallet can write
-        assert_eq!(
-            env::predecessor_account_id(),
-            contract.backend_wallet,
-            "Only backend wallet can store verifications"
+        ensure!(
+            contract.terms_version_of(&terms_hash).is_none(),
+            ErrorCode::TermsAlreadyRegistered,
+            "Terms version already registered"
         );
 
-        // Verify signature data matches the account being verified
-        assert_eq!(
-            signature_data.account_id, near_account_id,
-            "Signature account ID must match near_account_id"
+        let terms_version = contract.terms_versions.len();
+        contract.terms_versions.push(TermsVersion {
+            terms_hash: terms_hash.clone(),
+            registered_at: env::block_timestamp(),
+        });
+
+        contract.emit_admin_event(
+            "terms_version_registered",
+            &TermsVersionRegisteredEvent {
+                terms_version,
+                terms_hash,
+                by: caller.clone(),
+            },
         );
+        contract.emit_config_updated("current_terms_hash", caller);
+    }
+
+    /// Add an app ID to the signature allowlist (only callable by backend wallet)
+    ///
+    /// Once the allowlist is non-empty, every stored signature must carry an allowlisted app ID.
+    #[payable]
+    pub fn add_allowed_app_id(&mut self, app_id: String) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
 
-        assert_eq!(
-            signature_data.recipient,
-            env::current_account_id(),
-            "Signature recipient must match contract account"
+        let contract = self.contract_mut();
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can manage app IDs"
+        );
+        ensure!(
+            !app_id.is_empty() && app_id.len() <= MAX_APP_ID_LEN,
+            ErrorCode::InputTooLong,
+            "App ID must be 1 to {} bytes",
+            MAX_APP_ID_LEN
+        );
+        ensure!(
+            contract.allowed_app_ids.insert(app_id),
+            ErrorCode::AppIdAlreadyAllowed,
+            "App ID already allowed"
         );
 
-        // Verify the NEAR signature
-        Self::verify_near_signature(&signature_data);
+        contract.emit_config_updated("allowed_app_ids", caller);
+    }
 
-        // Prevent re-verification of accounts
-        assert!(
-            contract.verifications.get(&near_account_id).is_none(),
-            "NEAR account already verified"
+    /// Remove an app ID from the signature allowlist (only callable by backend wallet)
+    #[payable]
+    pub fn remove_allowed_app_id(&mut self, app_id: String) {
+        assert_one_yocto();
+        let caller = env::predecessor_account_id();
+
+        let contract = self.contract_mut();
+        ensure!(
+            contract.is_admin(&caller),
+            ErrorCode::Unauthorized,
+            "Only backend wallet can manage app IDs"
+        );
+        ensure!(
+            contract.allowed_app_ids.remove(&app_id),
+            ErrorCode::AppIdNotAllowed,
+            "App ID not in allowlist"
         );
 
-        // Create verification record (always use current version)
-        let verification = Verification {
-            near_account_id: near_account_id.clone(),
-            verified_at: env::block_timestamp(),
-            user_context_data,
-        };
+        contract.emit_config_updated("allowed_app_ids", caller);
+    }
 
-        // Store verification
-        contract.verifications.insert(
-            near_account_id.clone(),
-            VersionedVerification::from(verification),
-        );
+    /// Consistency checks applied by the integrity views
+    fn record_issues(key: &AccountId, record: &VersionedVerification) -> Vec<IntegrityIssue> {
+        let record = record.as_current();
+        let mut issues = Vec::new();
+        if record.near_account_id != *key {
+            issues.push(IntegrityIssue::AccountIdMismatch {
+                stored: record.near_account_id,
+            });
+        }
+        if record.user_context_data.len() > MAX_USER_CONTEXT_DATA_LEN {
+            issues.push(IntegrityIssue::ContextDataTooLong {
+                len: record.user_context_data.len() as u32,
+            });
+        }
+        let hash_matches = match record.context_storage {
+            ContextStorage::Full => {
+                Verification::hash_context(&record.user_context_data) == record.user_context_hash
+            }
+            ContextStorage::HashOnly | ContextStorage::Pruned => {
+                record.user_context_data.is_empty()
+            }
+        };
+        if !hash_matches {
+            issues.push(IntegrityIssue::ContextHashMismatch);
+        }
+        if record.verified_at == 0 || record.verified_at > env::block_timestamp() {
+            issues.push(IntegrityIssue::InvalidTimestamp {
+                verified_at: record.verified_at,
+            });
+        }
+        issues
+    }
 
-        // Emit event
-        emit_event(
-            "verification_stored",
-            &VerificationStoredEvent { near_account_id },
-        );
+    /// Borsh-encode up to `limit` (max 100) entries of `kind` starting at `from_index`
+    fn state_chunk_data(
+        &self,
+        kind: StateChunkKind,
+        from_index: u32,
+        limit: u32,
+    ) -> (u32, Vec<u8>) {
+        let take = std::cmp::min(limit, MAX_PAGE_SIZE) as usize;
+        let encoded = match kind {
+            StateChunkKind::Accounts => {
+                let entries: Vec<_> = self
+                    .verifications()
+                    .iter()
+                    .skip(from_index as usize)
+                    .take(take)
+                    .collect();
+                near_sdk::borsh::to_vec(&entries).map(|data| (entries.len(), data))
+            }
+            StateChunkKind::Consumers => {
+                let entries: Vec<&AccountId> = self
+                    .consumers()
+                    .map(|c| c.iter().skip(from_index as usize).take(take).collect())
+                    .unwrap_or_default();
+                near_sdk::borsh::to_vec(&entries).map(|data| (entries.len(), data))
+            }
+        };
+        match encoded {
+            Ok((count, data)) => (count as u32, data),
+            Err(e) => errors::panic_with_code(
+                ErrorCode::ExportSerialization,
+                &format!("Failed to serialize {:?} chunk: {}", kind, e),
+            ),
+        }
     }
 
     /// Verify NEAR signature (NEP-413 format)
@@ -370,10 +2886,18 @@ impl VersionedContract {
         let signature = &sig_data.signature.0;
 
         // Validate nonce length
-        assert_eq!(nonce.len(), 32, "Nonce must be exactly 32 bytes");
+        ensure!(
+            nonce.len() == 32,
+            ErrorCode::InvalidNonce,
+            "Nonce must be exactly 32 bytes"
+        );
 
         // Validate signature length
-        assert_eq!(signature.len(), 64, "Signature must be 64 bytes");
+        ensure!(
+            signature.len() == 64,
+            ErrorCode::InvalidSignatureFormat,
+            "Signature must be 64 bytes"
+        );
 
         // Step 1: Serialize the NEP-413 prefix tag
         let tag: u32 = 2147484061;
@@ -384,7 +2908,7 @@ impl VersionedContract {
         nonce_array.copy_from_slice(nonce);
 
         let payload = Nep413Payload {
-            message: sig_data.challenge.clone(),
+            message: sig_data.signed_message(),
             nonce: nonce_array,
             recipient: sig_data.recipient.to_string(),
             callback_url: None,
@@ -394,13 +2918,16 @@ impl VersionedContract {
         let payload_bytes = match near_sdk::borsh::to_vec(&payload) {
             Ok(bytes) => bytes,
             Err(e) => {
-                env::panic_str(&format!(
+                errors::panic_with_code(
+                    ErrorCode::PayloadSerialization,
+                    &format!(
                     "Failed to serialize NEP-413 payload: {:?}. Message: {}, Nonce len: {}, Recipient: {}",
                     e,
                     sig_data.challenge,
                     nonce.len(),
                     sig_data.recipient
-                ));
+                ),
+                );
             }
         };
 
@@ -413,17 +2940,25 @@ impl VersionedContract {
 
         // Step 5: Extract and validate the public key
         let public_key_data = sig_data.public_key.as_bytes();
-        assert_eq!(
-            public_key_data.len(),
-            33,
+        ensure!(
+            public_key_data.len() == 33,
+            ErrorCode::UnsupportedPublicKey,
             "Public key data should be 33 bytes (1 byte type + 32 bytes key)"
         );
 
         let key_type = public_key_data.first().copied().unwrap_or(255);
-        assert_eq!(key_type, 0, "Only ED25519 keys are supported");
+        ensure!(
+            key_type == 0,
+            ErrorCode::UnsupportedPublicKey,
+            "Only ED25519 keys are supported"
+        );
 
         let public_key_bytes = public_key_data.get(1..).unwrap_or(&[]);
-        assert_eq!(public_key_bytes.len(), 32, "Public key must be 32 bytes");
+        ensure!(
+            public_key_bytes.len() == 32,
+            ErrorCode::UnsupportedPublicKey,
+            "Public key must be 32 bytes"
+        );
 
         // Step 6: Convert to fixed-size arrays for ed25519_verify
         let mut sig_array = [0u8; 64];
@@ -435,8 +2970,9 @@ impl VersionedContract {
         // Step 7: Verify the signature against the SHA-256 hash
         let is_valid = env::ed25519_verify(&sig_array, &message_hash, &pk_array);
 
-        assert!(
+        ensure!(
             is_valid,
+            ErrorCode::InvalidSignature,
             "Invalid NEAR signature - NEP-413 verification failed"
         );
     }
@@ -458,8 +2994,21 @@ impl VersionedContract {
     }
 
     /// Check if an account is verified (public read)
+    ///
+    /// Only checks that the record's storage key exists; the record is never read.
     pub fn is_verified(&self, account_id: AccountId) -> bool {
-        self.verifications().get(&account_id).is_some()
+        self.verifications().contains_key(&account_id)
+    }
+
+    /// Check if an account has been verified for at least `min_verified_duration_ns` (public read)
+    pub fn is_account_verified_since(
+        &self,
+        account_id: AccountId,
+        min_verified_duration_ns: u64,
+    ) -> bool {
+        self.verifications().get(&account_id).is_some_and(|v| {
+            env::block_timestamp().saturating_sub(v.verified_at()) >= min_verified_duration_ns
+        })
     }
 
     /// Get the backend wallet address (public read)
@@ -467,37 +3016,307 @@ impl VersionedContract {
         self.backend_wallet().clone()
     }
 
+    /// Get the length and head hash of the admin audit chain (public read)
+    pub fn get_audit_head(&self) -> AuditHead {
+        match self {
+            Self::V1(_) => AuditHead {
+                length: 0,
+                head: [0; 32].into(),
+            },
+            Self::V2(c) => AuditHead {
+                length: u64::from(c.audit_log.len()),
+                head: c.audit_head.into(),
+            },
+        }
+    }
+
+    /// Get audit entries in chain order, starting at `from` (public read)
+    ///
+    /// `limit` is capped at 100. Recompute each `entry_hash` from its fields and check it
+    /// against the next entry's `prev_hash` and finally `get_audit_head()`.
+    pub fn get_audit_entries(&self, from: u64, limit: u32) -> Vec<AuditEntry> {
+        let Self::V2(c) = self else {
+            return Vec::new();
+        };
+        c.audit_log
+            .iter()
+            .skip(from as usize)
+            .take(std::cmp::min(limit, MAX_PAGE_SIZE) as usize)
+            .zip(from..)
+            .map(|(entry, seq)| AuditEntry {
+                seq,
+                kind: entry.kind.clone(),
+                prev_hash: entry.prev_hash.into(),
+                payload_hash: entry.payload_hash.into(),
+                block_height: entry.block_height,
+                entry_hash: entry.entry_hash().into(),
+            })
+            .collect()
+    }
+
+    /// Get registered terms versions, oldest first; the last one is current (public read)
+    pub fn get_terms_versions(&self) -> Vec<TermsVersion> {
+        match self {
+            Self::V1(_) => Vec::new(),
+            Self::V2(c) => c.terms_versions.iter().cloned().collect(),
+        }
+    }
+
+    /// Get the terms version an account accepted when verified, if recorded (public read)
+    pub fn get_consent(&self, account_id: AccountId) -> Option<Consent> {
+        let Self::V2(c) = self else {
+            return None;
+        };
+        let record = c.consents.get(&account_id)?;
+        let terms = c.terms_versions.get(record.terms_version)?;
+        Some(Consent {
+            terms_hash: terms.terms_hash.clone(),
+            terms_version: record.terms_version,
+            consented_at: record.consented_at,
+            superseded: record.terms_version + 1 < c.terms_versions.len(),
+        })
+    }
+
+    /// Get the NEP-413 public key linked to a verified account (public read)
+    ///
+    /// `None` if the account is not verified or was verified before keys were linked.
+    pub fn get_linked_key(&self, account_id: AccountId) -> Option<PublicKey> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(c) => c.linked_keys.get(&account_id).cloned(),
+        }
+    }
+
+    /// Get the citizen credited with referring an account, if any (public read)
+    pub fn get_referrer(&self, account_id: AccountId) -> Option<AccountId> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(c) => c.referrers.get(&account_id).cloned(),
+        }
+    }
+
+    /// Get the number of verifications an account has been credited for referring (public read)
+    pub fn get_referral_count(&self, account_id: AccountId) -> u32 {
+        match self {
+            Self::V1(_) => 0,
+            Self::V2(c) => c
+                .referral_counts
+                .get(&account_id)
+                .copied()
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Get a verified member's summary and opt-in profile (public read)
+    ///
+    /// Returns `None` if the account is not verified.
+    pub fn get_member_profile(&self, account_id: AccountId) -> Option<MemberInfo> {
+        let verification = VerificationSummary::from(self.verifications().get(&account_id)?);
+        let profile = match self {
+            Self::V1(_) => None,
+            Self::V2(c) => c.profiles.get(&account_id).cloned(),
+        };
+        Some(MemberInfo {
+            verification,
+            profile,
+        })
+    }
+
+    /// Get the number of queued verification requests (public read)
+    pub fn get_verification_queue_depth(&self) -> u32 {
+        self.verification_queue()
+            .map(|queue| queue.len())
+            .unwrap_or_default()
+    }
+
+    /// Get an account's queued verification request and its position, if any (public read)
+    ///
+    /// Position counts requests made earlier (ties broken by account ID), so it is stable
+    /// as other entries are removed.
+    pub fn get_verification_request(&self, account_id: AccountId) -> Option<QueuedRequest> {
+        let queue = self.verification_queue()?;
+        let requested_at = *queue.get(&account_id)?;
+        let position = queue
+            .iter()
+            .filter(|(other, other_at)| (**other_at, *other) < (requested_at, &account_id))
+            .count() as u32;
+        Some(QueuedRequest {
+            position,
+            requested_at,
+        })
+    }
+
+    /// Get the scoped permission granted to `account`, if any (public read)
+    ///
+    /// Expired grants are still returned until revoked; compare `expires_at` to the current time.
+    pub fn get_permission(&self, account: AccountId) -> Option<PermissionGrant> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(c) => c.permissions.get(&account).cloned(),
+        }
+    }
+
+    /// Get the pending backend wallet rotation, if any (public read)
+    pub fn get_backend_rotation(&self) -> Option<BackendRotation> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(c) => c.backend_rotation.clone(),
+        }
+    }
+
     /// Get total number of verified accounts (public read)
     pub fn get_verified_count(&self) -> u32 {
         self.verifications().len()
     }
 
+    /// Get the last backend heartbeat and whether it is stale (public read)
+    ///
+    /// Stale when no heartbeat was received within `backend_liveness_window_ns`,
+    /// including when none was ever sent.
+    pub fn get_backend_liveness(&self) -> BackendLiveness {
+        let last_heartbeat = match self {
+            Self::V1(_) => None,
+            Self::V2(c) => c.last_heartbeat.clone(),
+        };
+        let stale_for_ns = last_heartbeat
+            .as_ref()
+            .map(|heartbeat| env::block_timestamp().saturating_sub(heartbeat.timestamp));
+        BackendLiveness {
+            stale: stale_for_ns.is_none_or(|elapsed| elapsed > BACKEND_LIVENESS_WINDOW_NS),
+            last_heartbeat,
+            stale_for_ns,
+        }
+    }
+
     /// Check if the contract is paused (public read)
     pub fn is_paused(&self) -> bool {
         self.paused()
     }
 
+    /// Total citizen weight: one unit per verified account (sputnik-staking view, public read)
+    pub fn ft_total_supply(&self) -> U128 {
+        U128(u128::from(self.verifications().len()))
+    }
+
+    /// Citizen weight of an account: 1 if verified, 0 otherwise (sputnik-staking view, public read)
+    pub fn ft_balance_of(&self, account_id: AccountId) -> U128 {
+        citizen_weight::weight_of(self.verifications().contains_key(&account_id))
+    }
+
+    /// Get the DAO an account's citizen weight was delegated to, if any (public read)
+    pub fn get_weight_delegation(&self, account_id: AccountId) -> Option<AccountId> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(c) => c.weight_delegations.get(&account_id).cloned(),
+        }
+    }
+
+    /// Get all registered consumer contracts (public read)
+    pub fn get_consumers(&self) -> Vec<AccountId> {
+        self.consumers()
+            .map(|consumers| consumers.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Get app IDs accepted in signature data; empty means app binding is optional (public read)
+    pub fn get_allowed_app_ids(&self) -> Vec<String> {
+        match self {
+            Self::V1(_) => Vec::new(),
+            Self::V2(c) => c.allowed_app_ids.iter().cloned().collect(),
+        }
+    }
+
     /// Get paginated list of all verifications (public read)
-    pub fn list_verifications(&self, from_index: u32, limit: u32) -> Vec<Verification> {
-        self.verifications()
+    pub fn list_verifications(&self, from_index: u32, limit: u32) -> Page<Verification> {
+        let limit = std::cmp::min(limit, MAX_PAGE_SIZE);
+        let verifications = self.verifications();
+        let items = verifications
             .iter()
             .skip(from_index as usize)
-            .take(std::cmp::min(limit, 100) as usize)
+            .take(limit as usize)
             .map(|(_, v)| v.clone().into_current())
+            .collect();
+        Page::new(items, from_index, limit, verifications.len())
+    }
+
+    /// Recompute internal consistency of one account's record (public read)
+    pub fn verify_record_integrity(&self, account_id: AccountId) -> IntegrityReport {
+        let issues = match self.verifications().get(&account_id) {
+            Some(record) => Self::record_issues(&account_id, record),
+            None => vec![IntegrityIssue::NotFound],
+        };
+        IntegrityReport { account_id, issues }
+    }
+
+    /// Check a page of stored records in verification order, for audit sweeps (public read)
+    ///
+    /// `limit` is capped at 100. Returns one report per record, including clean ones.
+    pub fn verify_records_integrity(&self, from_index: u32, limit: u32) -> Vec<IntegrityReport> {
+        self.verifications()
+            .iter()
+            .skip(from_index as usize)
+            .take(std::cmp::min(limit, MAX_PAGE_SIZE) as usize)
+            .map(|(account_id, record)| IntegrityReport {
+                account_id: account_id.clone(),
+                issues: Self::record_issues(account_id, record),
+            })
             .collect()
     }
 
+    /// Get paginated summaries of accounts verified strictly before `timestamp` (public read)
+    ///
+    /// Pagination indexes the full verification list, so a page may contain fewer than
+    /// `limit` results. Records are stored in verification order, so once a page drops
+    /// an entry every later page will be empty. `total` counts all records.
+    pub fn list_verifications_before(
+        &self,
+        timestamp: u64,
+        from_index: u32,
+        limit: u32,
+    ) -> Page<VerificationSummary> {
+        let limit = std::cmp::min(limit, MAX_PAGE_SIZE);
+        let verifications = self.verifications();
+        let items = verifications
+            .iter()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .map(|(_, v)| VerificationSummary::from(v))
+            .filter(|summary| summary.verified_at < timestamp)
+            .collect();
+        Page::new(items, from_index, limit, verifications.len())
+    }
+
+    /// Get aggregate statistics for the verified set (public read)
+    pub fn get_statistics(&self) -> VerificationStatistics {
+        let verifications = self.verifications();
+        VerificationStatistics {
+            verified_count: verifications.len(),
+            first_verified_at: verifications
+                .values()
+                .next()
+                .map(VersionedVerification::verified_at),
+            last_verified_at: verifications
+                .values()
+                .next_back()
+                .map(VersionedVerification::verified_at),
+            paused: self.paused(),
+            state_version: self.get_state_version(),
+            record_version: CURRENT_VERIFICATION_VERSION,
+        }
+    }
+
     /// Batch check if multiple accounts are verified (public read)
     pub fn are_verified(&self, account_ids: Vec<AccountId>) -> Vec<bool> {
-        assert!(
+        ensure!(
             account_ids.len() <= MAX_BATCH_SIZE,
+            ErrorCode::BatchTooLarge,
             "Batch size exceeds maximum of {} accounts",
             MAX_BATCH_SIZE
         );
         let verifications = self.verifications();
         account_ids
             .iter()
-            .map(|id| verifications.get(id).is_some())
+            .map(|id| verifications.contains_key(id))
             .collect()
     }
 
@@ -506,8 +3325,9 @@ impl VersionedContract {
         &self,
         account_ids: Vec<AccountId>,
     ) -> Vec<Option<VerificationSummary>> {
-        assert!(
+        ensure!(
             account_ids.len() <= MAX_BATCH_SIZE,
+            ErrorCode::BatchTooLarge,
             "Batch size exceeds maximum of {} accounts",
             MAX_BATCH_SIZE
         );
@@ -518,11 +3338,213 @@ impl VersionedContract {
             .collect()
     }
 
+    /// Get the sequence number the next membership change will receive (public read)
+    pub fn get_membership_seq(&self) -> u64 {
+        match self.membership_log() {
+            Some((log, base)) => base + u64::from(log.len()),
+            None => u64::from(self.verifications().len()),
+        }
+    }
+
+    /// Get membership changes in sequence order, starting at `from_seq` (public read)
+    ///
+    /// Replaying from seq 0 always reproduces the full citizen set: changes made before
+    /// the V2 upgrade are reconstructed from verification order.
+    pub fn get_membership_diffs(&self, from_seq: u64, limit: u32) -> Vec<MembershipDiff> {
+        let limit = std::cmp::min(limit, MAX_PAGE_SIZE) as usize;
+        let (log, base) = match self.membership_log() {
+            Some((log, base)) => (Some(log), base),
+            None => (None, u64::from(self.verifications().len())),
+        };
+
+        let mut diffs = Vec::new();
+        if from_seq < base {
+            let take = std::cmp::min(limit as u64, base - from_seq) as usize;
+            diffs.extend(
+                self.verifications()
+                    .values()
+                    .skip(from_seq as usize)
+                    .take(take)
+                    .zip(from_seq..)
+                    .map(|(v, seq)| {
+                        let v = VerificationSummary::from(v);
+                        MembershipDiff {
+                            seq,
+                            account_id: v.near_account_id,
+                            change: MembershipChange::Added,
+                            timestamp: v.verified_at,
+                        }
+                    }),
+            );
+        }
+        if let Some(log) = log {
+            let start = std::cmp::max(from_seq, base);
+            diffs.extend(
+                log.iter()
+                    .skip((start - base) as usize)
+                    .take(limit - diffs.len())
+                    .zip(start..)
+                    .map(|(entry, seq)| MembershipDiff {
+                        seq,
+                        account_id: entry.account_id.clone(),
+                        change: entry.change.clone(),
+                        timestamp: entry.timestamp,
+                    }),
+            );
+        }
+        diffs
+    }
+
+    /// Get retained events in sequence order, starting at `from_seq` (public read)
+    ///
+    /// Only the last `EVENT_LOG_CAPACITY` events are kept; if the first returned `seq`
+    /// is greater than `from_seq`, older events have been overwritten.
+    pub fn get_events(&self, from_seq: u64, limit: u32) -> Vec<IndexedEvent> {
+        let Some((log, next_seq)) = self.event_log() else {
+            return Vec::new();
+        };
+        let oldest = next_seq - u64::from(log.len());
+        let start = std::cmp::max(from_seq, oldest);
+        let end = std::cmp::min(
+            next_seq,
+            start.saturating_add(u64::from(std::cmp::min(limit, MAX_PAGE_SIZE))),
+        );
+
+        (start..end)
+            .filter_map(|seq| {
+                log.get((seq % u64::from(EVENT_LOG_CAPACITY)) as u32)
+                    .map(|entry| IndexedEvent {
+                        seq,
+                        kind: entry.kind.clone(),
+                        payload_hash: entry.payload_hash.into(),
+                        block_height: entry.block_height,
+                    })
+            })
+            .collect()
+    }
+
+    /// Export a Borsh-encoded slice of contract state for audits (public read)
+    ///
+    /// `limit` is capped at 100. Chunks exported with `limit = 100` from indexes that are
+    /// multiples of 100 hash into `get_state_root()`.
+    pub fn export_state_chunk(
+        &self,
+        kind: StateChunkKind,
+        from_index: u32,
+        limit: u32,
+    ) -> StateChunk {
+        let (count, data) = self.state_chunk_data(kind, from_index, limit);
+        StateChunk {
+            kind,
+            from_index,
+            count,
+            hash: env::sha256_array(&data).into(),
+            data: Base64VecU8(data),
+        }
+    }
+
+    /// Get a digest of all exportable state (public read)
+    ///
+    /// Reads every record, so view gas grows with the verified set.
+    pub fn get_state_root(&self) -> StateRoot {
+        let mut chunk_hashes = Vec::new();
+        for kind in StateChunkKind::ALL {
+            let len = match kind {
+                StateChunkKind::Accounts => self.verifications().len(),
+                StateChunkKind::Consumers => self.consumers().map_or(0, |c| c.len()),
+            };
+            for from_index in (0..len).step_by(MAX_PAGE_SIZE as usize) {
+                let (_, data) = self.state_chunk_data(kind, from_index, MAX_PAGE_SIZE);
+                chunk_hashes.extend_from_slice(&env::sha256_array(&data));
+            }
+        }
+
+        StateRoot {
+            root: env::sha256_array(&chunk_hashes).into(),
+            chunk_size: MAX_PAGE_SIZE,
+            chunk_count: (chunk_hashes.len() / 32) as u32,
+        }
+    }
+
+    /// Get the contract's balance, storage usage and estimated remaining verification
+    /// capacity (public read)
+    pub fn get_capacity_estimate(&self) -> CapacityEstimate {
+        CapacityEstimate::current(
+            self.low_balance_threshold(),
+            u64::from(self.verifications().len()),
+        )
+    }
+
+    /// Get the semver of the cross-contract interface (public read)
+    pub fn interface_version(&self) -> String {
+        INTERFACE_VERSION.to_string()
+    }
+
+    /// Get every tunable parameter and the code version of this deployment (public read)
+    pub fn get_config(&self) -> ContractConfig {
+        ContractConfig {
+            config_version: CONFIG_VERSION,
+            code_version: env!("CARGO_PKG_VERSION").to_string(),
+            interface_version: INTERFACE_VERSION.to_string(),
+            state_version: self.get_state_version(),
+            record_version: CURRENT_VERIFICATION_VERSION,
+            backend_wallet: self.backend_wallet().clone(),
+            max_user_context_data_len: MAX_USER_CONTEXT_DATA_LEN as u32,
+            max_batch_size: MAX_BATCH_SIZE as u32,
+            max_batch_ops: match self {
+                Self::V1(_) => MAX_BATCH_OPS as u32,
+                Self::V2(c) => c.max_batch_ops() as u32,
+            },
+            max_page_size: MAX_PAGE_SIZE,
+            max_consumers: MAX_CONSUMERS,
+            consumer_notification_tgas: CONSUMER_NOTIFICATION_GAS.as_tgas(),
+            event_log_capacity: EVENT_LOG_CAPACITY,
+            max_verification_queue_len: MAX_VERIFICATION_QUEUE_LEN,
+            verification_request_deposit: U128(VERIFICATION_REQUEST_DEPOSIT.as_yoctonear()),
+            max_referrals_per_account: MAX_REFERRALS_PER_ACCOUNT,
+            low_balance_threshold: U128(self.low_balance_threshold().as_yoctonear()),
+            backend_liveness_window_ns: BACKEND_LIVENESS_WINDOW_NS,
+            funder: match self {
+                Self::V1(_) => None,
+                Self::V2(c) => c.funder.clone(),
+            },
+            top_up_request_interval_ns: TOP_UP_REQUEST_INTERVAL_NS,
+            weight_dao: match self {
+                Self::V1(_) => None,
+                Self::V2(c) => c.weight_dao.clone(),
+            },
+            allowed_app_ids: self.get_allowed_app_ids(),
+            recipient_policy: self.recipient_policy(),
+            hash_only_storage: self.hash_only_storage(),
+            current_terms_hash: self
+                .get_terms_versions()
+                .pop()
+                .map(|terms| terms.terms_hash),
+            mpc_public_key: match self {
+                Self::V1(_) => None,
+                Self::V2(c) => c.mpc_public_key.clone().map(Base64VecU8::from),
+            },
+        }
+    }
+
+    /// Get every error code this contract can raise, with descriptions (public read)
+    ///
+    /// Panic messages are formatted as `"<CODE>: <message>"`; match on the code.
+    pub fn get_error_catalog(&self) -> Vec<ErrorCatalogEntry> {
+        ErrorCode::ALL
+            .iter()
+            .map(|code| ErrorCatalogEntry {
+                code: *code,
+                description: code.description().to_string(),
+            })
+            .collect()
+    }
+
     /// Get contract state version (for diagnostics)
     pub fn get_state_version(&self) -> u8 {
         match self {
             Self::V1(_) => 1,
-            // Self::V2(_) => 2,
+            Self::V2(_) => 2,
         }
     }
 }
@@ -4,6 +4,7 @@ use crate::helpers::{generate_nep413_signature, init, nonce_to_base64};
 use allure_rs::prelude::*;
 use near_workspaces::types::{Gas, NearToken};
 use serde_json::json;
+use verified_accounts::Page;
 
 #[allure_parent_suite("Near Citizens House")]
 #[allure_suite_label("Verified Accounts Integration Tests")]
@@ -58,7 +59,7 @@ async fn test_pagination_limit_capped_at_100() -> anyhow::Result<()> {
     });
 
     // Request more than 100 items - should be capped at 100
-    let verifications: Vec<serde_json::Value> = contract
+    let verifications: Page<serde_json::Value> = contract
         .view("list_verifications")
         .args_json(json!({"from_index": 0, "limit": 200}))
         .await?
@@ -67,21 +68,28 @@ async fn test_pagination_limit_capped_at_100() -> anyhow::Result<()> {
     step("Verify pagination is capped at 100 items", || {
         // The contract should cap limit at 100 internally
         assert_eq!(
-            verifications.len(),
+            verifications.items.len(),
             100,
             "Pagination should cap at 100 items even when requesting 200"
         );
+        assert_eq!(verifications.total, 105);
+        assert_eq!(verifications.next_index, Some(100));
     });
 
     // Verify we can get the remaining 5 accounts
-    let remaining: Vec<serde_json::Value> = contract
+    let remaining: Page<serde_json::Value> = contract
         .view("list_verifications")
         .args_json(json!({"from_index": 100, "limit": 10}))
         .await?
         .json()?;
 
     step("Verify remaining 5 verifications can be fetched", || {
-        assert_eq!(remaining.len(), 5, "Should get remaining 5 verifications");
+        assert_eq!(
+            remaining.items.len(),
+            5,
+            "Should get remaining 5 verifications"
+        );
+        assert_eq!(remaining.next_index, None);
     });
 
     Ok(())
@@ -101,14 +109,14 @@ async fn test_pagination_from_index_beyond_data() -> anyhow::Result<()> {
     let (_worker, contract, _backend) = init().await?;
 
     // Request from index beyond existing data
-    let verifications: Vec<serde_json::Value> = contract
+    let verifications: Page<serde_json::Value> = contract
         .view("list_verifications")
         .args_json(json!({"from_index": 1000, "limit": 10}))
         .await?
         .json()?;
 
     step("Verify empty result when from_index is beyond data", || {
-        assert_eq!(verifications.len(), 0);
+        assert_eq!(verifications.items.len(), 0);
     });
 
     Ok(())
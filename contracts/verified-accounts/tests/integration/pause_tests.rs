@@ -5,6 +5,7 @@ use allure_rs::prelude::*;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use near_workspaces::types::{Gas, NearToken};
 use serde_json::json;
+use verified_accounts::Page;
 
 #[allure_parent_suite("Near Citizens House")]
 #[allure_suite_label("Verified Accounts Integration Tests")]
@@ -243,7 +244,7 @@ async fn test_pause_allows_read_operations() -> anyhow::Result<()> {
         assert_eq!(count, 1, "Count should be accessible when paused");
     });
 
-    let accounts: Vec<serde_json::Value> = contract
+    let accounts: Page<serde_json::Value> = contract
         .view("list_verifications")
         .args_json(json!({"from_index": 0, "limit": 10}))
         .await?
@@ -251,7 +252,7 @@ async fn test_pause_allows_read_operations() -> anyhow::Result<()> {
 
     step("Verify list_verifications works when paused", || {
         assert_eq!(
-            accounts.len(),
+            accounts.items.len(),
             1,
             "list_verifications should work when paused"
         );
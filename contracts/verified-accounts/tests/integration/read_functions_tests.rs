@@ -3,6 +3,7 @@
 use crate::helpers::init;
 use allure_rs::prelude::*;
 use serde_json::json;
+use verified_accounts::Page;
 
 #[allure_parent_suite("Near Citizens House")]
 #[allure_suite_label("Verified Accounts Integration Tests")]
@@ -47,14 +48,14 @@ async fn test_is_verified_returns_false_for_unverified() -> anyhow::Result<()> {
 async fn test_list_verifications_empty() -> anyhow::Result<()> {
     let (_worker, contract, _backend) = init().await?;
 
-    let verifications: Vec<serde_json::Value> = contract
+    let verifications: Page<serde_json::Value> = contract
         .view("list_verifications")
         .args_json(json!({"from_index": 0, "limit": 10}))
         .await?
         .json()?;
 
     step("Verify list_verifications returns empty list", || {
-        assert_eq!(verifications.len(), 0);
+        assert_eq!(verifications.items.len(), 0);
     });
 
     Ok(())
@@ -5,6 +5,7 @@ use allure_rs::prelude::*;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use near_workspaces::types::{Gas, NearToken};
 use serde_json::json;
+use verified_accounts::Page;
 
 #[allure_parent_suite("Near Citizens House")]
 #[allure_suite_label("Verified Accounts Integration Tests")]
@@ -334,25 +335,27 @@ async fn test_list_verifications_pagination() -> anyhow::Result<()> {
     });
 
     // Test pagination - get first 2
-    let page1: Vec<serde_json::Value> = contract
+    let page1: Page<serde_json::Value> = contract
         .view("list_verifications")
         .args_json(json!({"from_index": 0, "limit": 2}))
         .await?
         .json()?;
 
     step("Verify first page returns 2 items", || {
-        assert_eq!(page1.len(), 2);
+        assert_eq!(page1.items.len(), 2);
+        assert_eq!(page1.next_index, Some(2));
     });
 
     // Test pagination - get remaining 1
-    let page2: Vec<serde_json::Value> = contract
+    let page2: Page<serde_json::Value> = contract
         .view("list_verifications")
         .args_json(json!({"from_index": 2, "limit": 2}))
         .await?
         .json()?;
 
     step("Verify second page returns 1 item", || {
-        assert_eq!(page2.len(), 1);
+        assert_eq!(page2.items.len(), 1);
+        assert_eq!(page2.next_index, None);
     });
 
     Ok(())
@@ -10,6 +10,7 @@ use near_workspaces::{Account, Contract};
 use serde_json::json;
 use std::env;
 use std::time::Instant;
+use verified_accounts::Page;
 
 const CHALLENGE_MESSAGE: &str = "Identify myself";
 const DEFAULT_STRESS_TOTAL: usize = 1000;
@@ -106,14 +107,14 @@ async fn test_stress_bulk_verifications_and_pagination() -> anyhow::Result<()> {
     });
 
     let page_size = 50usize;
-    let page1: Vec<serde_json::Value> = contract
+    let page1: Page<serde_json::Value> = contract
         .view("list_verifications")
         .args_json(json!({"from_index": 0u32, "limit": page_size as u32}))
         .await?
         .json()?;
 
     step("Verify first page has expected size", || {
-        assert_eq!(page1.len(), total.min(page_size));
+        assert_eq!(page1.items.len(), total.min(page_size));
     });
 
     let sample_verified: bool = contract
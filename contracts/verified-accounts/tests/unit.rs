@@ -54,3 +54,75 @@ mod interface_validation_tests;
 
 #[path = "unit/storage_stability_tests.rs"]
 mod storage_stability_tests;
+
+#[path = "unit/consumer_tests.rs"]
+mod consumer_tests;
+
+#[path = "unit/error_code_tests.rs"]
+mod error_code_tests;
+
+#[path = "unit/config_tests.rs"]
+mod config_tests;
+
+#[path = "unit/membership_diff_tests.rs"]
+mod membership_diff_tests;
+
+#[path = "unit/event_log_tests.rs"]
+mod event_log_tests;
+
+#[path = "unit/rotation_tests.rs"]
+mod rotation_tests;
+
+#[path = "unit/batch_tests.rs"]
+mod batch_tests;
+
+#[path = "unit/state_export_tests.rs"]
+mod state_export_tests;
+
+#[path = "unit/app_binding_tests.rs"]
+mod app_binding_tests;
+
+#[path = "unit/recipient_policy_tests.rs"]
+mod recipient_policy_tests;
+
+#[path = "unit/interface_fixture_tests.rs"]
+mod interface_fixture_tests;
+
+#[path = "unit/integrity_tests.rs"]
+mod integrity_tests;
+
+#[path = "unit/permission_tests.rs"]
+mod permission_tests;
+
+#[path = "unit/audit_log_tests.rs"]
+mod audit_log_tests;
+
+#[path = "unit/verification_queue_tests.rs"]
+mod verification_queue_tests;
+
+#[path = "unit/data_minimization_tests.rs"]
+mod data_minimization_tests;
+
+#[path = "unit/consent_tests.rs"]
+mod consent_tests;
+
+#[path = "unit/mpc_tests.rs"]
+mod mpc_tests;
+
+#[path = "unit/citizen_weight_tests.rs"]
+mod citizen_weight_tests;
+
+#[path = "unit/profile_tests.rs"]
+mod profile_tests;
+
+#[path = "unit/referral_tests.rs"]
+mod referral_tests;
+
+#[path = "unit/capacity_tests.rs"]
+mod capacity_tests;
+
+#[path = "unit/linked_key_tests.rs"]
+mod linked_key_tests;
+
+#[path = "unit/liveness_tests.rs"]
+mod liveness_tests;
@@ -32,12 +32,16 @@ fn test_unauthorized_write() {
                     challenge: "test".to_string(),
                     nonce: vec![0; 32].into(),
                     recipient: accounts(0),
+                    app_id: None,
+                    terms_hash: None,
+                    referred_by: None,
                 };
 
                 contract.store_verification(
                     accounts(2),
                     sig_data,
                     "test_user_context_data".to_string(),
+                    None,
                 );
             },
             "Only backend wallet can store verifications",
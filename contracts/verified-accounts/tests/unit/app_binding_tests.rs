@@ -0,0 +1,184 @@
+//! Signature app binding tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, create_valid_signature_for_app,
+    get_context, parse_event, ConfigUpdatedEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::testing_env;
+use verified_accounts::VersionedContract;
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("App Binding")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "security", "signature")]
+#[allure_description(
+    "Verifies that app IDs are optional without an allowlist and enforced once one is configured."
+)]
+#[allure_test]
+#[test]
+fn test_app_id_allowlist_enforcement() {
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    step(
+        "Without an allowlist, app-bound and plain signatures are accepted",
+        || {
+            let user = accounts(2);
+            let signer = create_signer(&user);
+            let sig_data = create_valid_signature_for_app(
+                &signer,
+                &user,
+                "Identify myself",
+                &[1; 32],
+                &accounts(0),
+                Some("other-dapp"),
+            );
+            contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
+            assert!(contract.is_verified(user));
+        },
+    );
+
+    step("Configure the allowlist", || {
+        contract.add_allowed_app_id("citizens-house".to_string());
+        assert_eq!(contract.get_allowed_app_ids(), vec!["citizens-house"]);
+        assert_eq!(
+            contract.get_config().allowed_app_ids,
+            vec!["citizens-house"]
+        );
+
+        let event: ConfigUpdatedEvent =
+            parse_event(&get_logs(), "config_updated").expect("config_updated event not found");
+        assert_eq!(event.field, "allowed_app_ids");
+    });
+
+    step("Reject missing and unknown app IDs", || {
+        let user = accounts(3);
+        let signer = create_signer(&user);
+        let plain =
+            create_valid_signature(&signer, &user, "Identify myself", &[2; 32], &accounts(0));
+        assert_panic_with(
+            || contract.store_verification(user.clone(), plain, "ctx".to_string(), None),
+            "ERR_APP_ID_NOT_ALLOWED: Signature must include an allowed app ID",
+        );
+
+        let other = create_valid_signature_for_app(
+            &signer,
+            &user,
+            "Identify myself",
+            &[3; 32],
+            &accounts(0),
+            Some("other-dapp"),
+        );
+        assert_panic_with(
+            || contract.store_verification(user.clone(), other, "ctx".to_string(), None),
+            "ERR_APP_ID_NOT_ALLOWED: App ID other-dapp is not allowed",
+        );
+    });
+
+    step("Accept an allowlisted app ID", || {
+        let user = accounts(3);
+        let signer = create_signer(&user);
+        let sig_data = create_valid_signature_for_app(
+            &signer,
+            &user,
+            "Identify myself",
+            &[4; 32],
+            &accounts(0),
+            Some("citizens-house"),
+        );
+        contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
+        assert!(contract.is_verified(user));
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("App Binding")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "security", "signature")]
+#[allure_description(
+    "Verifies that the app ID is part of the signed message, so it cannot be swapped after signing."
+)]
+#[allure_test]
+#[test]
+fn test_app_id_is_covered_by_signature() {
+    let mut contract = step("Initialize contract with an allowlist", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        let mut contract = VersionedContract::new(backend);
+        contract.add_allowed_app_id("citizens-house".to_string());
+        contract
+    });
+
+    step("Relabel a signature collected for another app", || {
+        let user = accounts(2);
+        let signer = create_signer(&user);
+        let mut sig_data = create_valid_signature_for_app(
+            &signer,
+            &user,
+            "Identify myself",
+            &[1; 32],
+            &accounts(0),
+            Some("other-dapp"),
+        );
+        sig_data.app_id = Some("citizens-house".to_string());
+        assert_panic_with(
+            || contract.store_verification(user, sig_data, "ctx".to_string(), None),
+            "ERR_INVALID_SIGNATURE",
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("App Binding")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "admin", "validation")]
+#[allure_description("Verifies allowlist management authorization and validation.")]
+#[allure_test]
+#[test]
+fn test_app_id_allowlist_management() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Reject invalid and duplicate app IDs", || {
+        assert_panic_with(
+            || contract.add_allowed_app_id(String::new()),
+            "ERR_INPUT_TOO_LONG",
+        );
+        assert_panic_with(
+            || contract.add_allowed_app_id("a".repeat(65)),
+            "ERR_INPUT_TOO_LONG",
+        );
+        contract.add_allowed_app_id("citizens-house".to_string());
+        assert_panic_with(
+            || contract.add_allowed_app_id("citizens-house".to_string()),
+            "ERR_APP_ID_ALREADY_ALLOWED",
+        );
+    });
+
+    step("Remove app IDs", || {
+        contract.remove_allowed_app_id("citizens-house".to_string());
+        assert!(contract.get_allowed_app_ids().is_empty());
+        assert_panic_with(
+            || contract.remove_allowed_app_id("citizens-house".to_string()),
+            "ERR_APP_ID_NOT_ALLOWED: App ID not in allowlist",
+        );
+    });
+
+    step("Reject non-backend callers", || {
+        testing_env!(get_context(accounts(2)).build());
+        assert_panic_with(
+            || contract.add_allowed_app_id("citizens-house".to_string()),
+            "ERR_UNAUTHORIZED: Only backend wallet can manage app IDs",
+        );
+    });
+}
@@ -0,0 +1,86 @@
+//! Admin audit hash chain tests for verified-accounts contract
+
+use super::helpers::{create_signer, create_valid_signature, get_context};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::testing_env;
+use near_sdk::{env, CryptoHash};
+use verified_accounts::VersionedContract;
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Audit Log")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "audit", "admin")]
+#[allure_description(
+    "Verifies that admin actions form a verifiable hash chain ending at get_audit_head, and user writes are not included."
+)]
+#[allure_test]
+#[test]
+fn test_admin_actions_form_hash_chain() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).block_height(7).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Empty chain has a zero head", || {
+        let head = contract.get_audit_head();
+        assert_eq!(head.length, 0);
+        assert_eq!(<CryptoHash>::from(head.head), [0; 32]);
+    });
+
+    let logs = step("Perform admin actions and a verification", || {
+        contract.pause();
+        contract.unpause();
+        let user = accounts(2);
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        contract.store_verification(user, sig_data, "ctx".to_string(), None);
+        contract.register_consumer(accounts(3));
+        get_logs()
+    });
+
+    step("Chain contains only admin actions, linked by hash", || {
+        let entries = contract.get_audit_entries(0, 100);
+        let kinds: Vec<&str> = entries.iter().map(|e| e.kind.as_str()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "contract_paused",
+                "contract_unpaused",
+                "consumer_registered"
+            ]
+        );
+
+        let mut prev: CryptoHash = [0; 32];
+        for entry in &entries {
+            assert_eq!(<CryptoHash>::from(entry.prev_hash), prev);
+
+            let mut preimage = prev.to_vec();
+            preimage.extend_from_slice(&<CryptoHash>::from(entry.payload_hash));
+            preimage.extend_from_slice(&entry.block_height.to_le_bytes());
+            let recomputed = env::sha256_array(&preimage);
+            assert_eq!(<CryptoHash>::from(entry.entry_hash), recomputed);
+            prev = recomputed;
+        }
+
+        let head = contract.get_audit_head();
+        assert_eq!(head.length, 3);
+        assert_eq!(<CryptoHash>::from(head.head), prev);
+    });
+
+    step("Payload hash matches the emitted log line", || {
+        let entry = &contract.get_audit_entries(2, 1)[0];
+        assert_eq!(entry.seq, 2);
+        assert_eq!(entry.block_height, 7);
+        let log = logs
+            .iter()
+            .find(|log| log.contains("\"event\":\"consumer_registered\""))
+            .expect("consumer_registered log not found");
+        assert_eq!(
+            <CryptoHash>::from(entry.payload_hash),
+            env::sha256_array(log.as_bytes())
+        );
+    });
+}
@@ -0,0 +1,202 @@
+//! Batched backend operation tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, get_context, parse_all_events,
+    parse_event, BatchExecutedEvent, VerificationStoredEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::{env, testing_env, AccountId, Gas, NearToken};
+use verified_accounts::{BackendOp, BackendOpResult, VersionedContract};
+
+fn store_op(user: &AccountId, nonce: u8) -> BackendOp {
+    let signer = create_signer(user);
+    BackendOp::Store {
+        near_account_id: user.clone(),
+        signature_data: create_valid_signature(
+            &signer,
+            user,
+            "Identify myself",
+            &[nonce; 32],
+            &accounts(0),
+        ),
+        user_context_data: "ctx".to_string(),
+        callback: None,
+    }
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Batch Operations")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "batch", "write")]
+#[allure_description(
+    "Verifies that execute_batch applies every store operation and reports per-op results in the return value and event."
+)]
+#[allure_test]
+#[test]
+fn test_execute_batch_stores_all_operations() {
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    let results = step("Execute a batch of two stores", || {
+        contract.execute_batch(vec![store_op(&accounts(2), 1), store_op(&accounts(3), 2)])
+    });
+
+    step("Verify results, state and event", || {
+        let stored: Vec<VerificationStoredEvent> =
+            parse_all_events(&get_logs(), "verification_stored");
+        let expected = vec![
+            BackendOpResult {
+                index: 0,
+                op: "store".to_string(),
+                near_account_id: accounts(2),
+                correlation_id: Some(stored[0].correlation_id.clone()),
+            },
+            BackendOpResult {
+                index: 1,
+                op: "store".to_string(),
+                near_account_id: accounts(3),
+                correlation_id: Some(stored[1].correlation_id.clone()),
+            },
+        ];
+        assert_ne!(stored[0].correlation_id, stored[1].correlation_id);
+        assert_eq!(results, expected);
+        assert!(contract.is_verified(accounts(2)));
+        assert!(contract.is_verified(accounts(3)));
+
+        let event: BatchExecutedEvent =
+            parse_event(&get_logs(), "batch_executed").expect("batch_executed event not found");
+        assert_eq!(event.by, accounts(1));
+        assert_eq!(event.results, expected);
+    });
+
+    step("Verify the op JSON shape", || {
+        let json = near_sdk::serde_json::to_value(store_op(&accounts(4), 3)).unwrap();
+        assert_eq!(json["op"], "store");
+        assert_eq!(json["near_account_id"], accounts(4).to_string());
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Batch Operations")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "batch", "security")]
+#[allure_description(
+    "Verifies that execute_batch rejects unauthorized callers, oversized batches, and failing operations."
+)]
+#[allure_test]
+#[test]
+fn test_execute_batch_rejections() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(0)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Reject unauthorized caller", || {
+        assert_panic_with(
+            || {
+                contract.execute_batch(vec![]);
+            },
+            "ERR_UNAUTHORIZED: Only backend wallet can execute batches",
+        );
+    });
+
+    step("Reject oversized batch", || {
+        testing_env!(get_context(accounts(1)).build());
+        let max = contract.get_config().max_batch_ops as u8;
+        let ops = (0..=max)
+            .map(|i| store_op(&format!("user{}.near", i).parse().unwrap(), i))
+            .collect();
+        assert_panic_with(
+            || {
+                contract.execute_batch(ops);
+            },
+            "ERR_BATCH_TOO_LARGE",
+        );
+    });
+
+    step("Failing operation aborts the batch", || {
+        assert_panic_with(
+            || {
+                contract.execute_batch(vec![store_op(&accounts(2), 1), store_op(&accounts(2), 2)]);
+            },
+            "ERR_ALREADY_VERIFIED",
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Batch Operations")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "batch", "gas")]
+#[allure_description(
+    "Verifies that max_batch_ops shrinks with the registered consumers and weight DAO, and that a maximal batch with the maximum consumers, callbacks and a top-up request fits in 300 TGas."
+)]
+#[allure_test]
+#[test]
+fn test_execute_batch_fits_prepaid_gas() {
+    let prepaid_gas = Gas::from_tgas(300);
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step(
+        "Register the maximum consumers, a weight DAO and a funder",
+        || {
+            assert_eq!(contract.get_config().max_batch_ops, 10);
+            let max_consumers = contract.get_config().max_consumers;
+            for i in 0..max_consumers {
+                contract.register_consumer(format!("consumer{}.near", i).parse().unwrap());
+            }
+            contract.set_weight_dao(Some("dao.near".parse().unwrap()));
+            contract.set_funder(Some("funder.near".parse().unwrap()));
+            assert!(contract.get_config().max_batch_ops < 10);
+        },
+    );
+
+    step("A maximal batch stays within the prepaid gas", || {
+        testing_env!(get_context(accounts(1))
+            .prepaid_gas(prepaid_gas)
+            .account_balance(NearToken::from_near(1))
+            .build());
+        let max = contract.get_config().max_batch_ops as u8;
+        let ops: Vec<BackendOp> = (0..max)
+            .map(|i| {
+                let mut op = store_op(&format!("user{}.near", i).parse().unwrap(), i);
+                let BackendOp::Store { callback, .. } = &mut op;
+                *callback = Some((accounts(3), "on_verified".to_string()));
+                op
+            })
+            .collect();
+        assert_eq!(contract.execute_batch(ops).len(), usize::from(max));
+
+        // used_gas includes the gas attached to every promise the batch created
+        assert!(
+            env::used_gas() <= prepaid_gas,
+            "batch uses {} of {} prepaid gas",
+            env::used_gas(),
+            prepaid_gas
+        );
+    });
+
+    step("One more operation is rejected", || {
+        testing_env!(get_context(accounts(1)).prepaid_gas(prepaid_gas).build());
+        let max = contract.get_config().max_batch_ops as u8;
+        let ops = (0..=max)
+            .map(|i| store_op(&format!("late{}.near", i).parse().unwrap(), i))
+            .collect();
+        assert_panic_with(
+            || {
+                contract.execute_batch(ops);
+            },
+            "ERR_BATCH_TOO_LARGE",
+        );
+    });
+}
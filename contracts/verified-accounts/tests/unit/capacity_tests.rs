@@ -0,0 +1,289 @@
+//! Low balance sentinel and capacity estimate tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, get_context, parse_event,
+    LowBalanceWarningEvent, TopUpRequestedEvent, VerificationStoredEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::{env, testing_env, AccountId, NearToken};
+use verified_accounts::VersionedContract;
+
+const ONE_NEAR: u128 = 1_000_000_000_000_000_000_000_000;
+
+/// The mock VM credits the attached 1 yoctoNEAR to the account balance
+const DEPOSIT: u128 = 1;
+
+fn store(contract: &mut VersionedContract, user: &AccountId) {
+    let signer = create_signer(user);
+    let sig_data = create_valid_signature(&signer, user, "Identify myself", &[1; 32], &accounts(0));
+    contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
+}
+
+/// `correlation_id` of the verification stored in the current context
+fn stored_correlation_id() -> Option<String> {
+    parse_event::<VerificationStoredEvent>(&get_logs(), "verification_stored")
+        .map(|event| event.correlation_id)
+}
+
+fn set_balance(account_balance: NearToken) {
+    set_balance_at(account_balance, 0);
+}
+
+fn set_balance_at(account_balance: NearToken, block_timestamp: u64) {
+    testing_env!(get_context(accounts(1))
+        .account_balance(account_balance)
+        .storage_usage(0)
+        .block_timestamp(block_timestamp)
+        .build());
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Capacity")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "capacity", "read")]
+#[allure_description(
+    "Verifies that get_capacity_estimate reports the available balance and the verifications it can pay storage for."
+)]
+#[allure_test]
+#[test]
+fn test_capacity_estimate() {
+    let contract = step("Initialize contract with 10 NEAR", || {
+        set_balance(NearToken::from_near(10));
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Estimate reflects balance and default threshold", || {
+        let estimate = contract.get_capacity_estimate();
+        assert_eq!(estimate.account_balance, U128(10 * ONE_NEAR + DEPOSIT));
+        assert_eq!(estimate.storage_usage, 0);
+        assert_eq!(estimate.available_balance, U128(10 * ONE_NEAR + DEPOSIT));
+        assert_eq!(estimate.low_balance_threshold, U128(5 * ONE_NEAR));
+        // Nothing verified yet: 6_000 bytes at 10^19 yoctoNEAR per byte is 0.06 NEAR
+        assert_eq!(estimate.bytes_per_verification, 6_000);
+        assert_eq!(estimate.estimated_verifications_remaining, 166);
+    });
+
+    step(
+        "Storage staking is excluded from the available balance",
+        || {
+            testing_env!(get_context(accounts(1))
+                .account_balance(NearToken::from_near(10))
+                .storage_usage(100_000)
+                .build());
+            let estimate = contract.get_capacity_estimate();
+            assert_eq!(estimate.available_balance, U128(9 * ONE_NEAR + DEPOSIT));
+            assert_eq!(estimate.estimated_verifications_remaining, 150);
+        },
+    );
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Capacity")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "capacity", "read")]
+#[allure_description(
+    "Verifies that the estimate averages storage over the verified count, and that the fallback used before any verification covers records with the maximum context length."
+)]
+#[allure_test]
+#[test]
+fn test_capacity_estimate_with_max_length_context() {
+    let mut contract = step("Initialize contract with 10 NEAR", || {
+        set_balance(NearToken::from_near(10));
+        VersionedContract::new(accounts(1))
+    });
+
+    let users = [accounts(2), accounts(3), accounts(4)];
+    step("Store verifications with 4 KiB of context", || {
+        for user in &users {
+            let signer = create_signer(user);
+            let sig_data =
+                create_valid_signature(&signer, user, "Identify myself", &[1; 32], &accounts(0));
+            contract.store_verification(user.clone(), sig_data, "x".repeat(4096), None);
+        }
+    });
+
+    let contract = step(
+        "Flush collections to storage, as at the end of a call",
+        || {
+            env::state_write(&contract);
+            drop(contract);
+            env::state_read::<VersionedContract>().unwrap()
+        },
+    );
+
+    step("Estimate uses the measured bytes per verification", || {
+        let estimate = contract.get_capacity_estimate();
+        assert!(estimate.storage_usage > 3 * 4096);
+        assert_eq!(
+            estimate.bytes_per_verification,
+            estimate.storage_usage / users.len() as u64
+        );
+        assert!(
+            estimate.bytes_per_verification <= 6_000,
+            "fallback of 6_000 bytes is below a max-length record ({} bytes)",
+            estimate.bytes_per_verification
+        );
+        assert_eq!(
+            u128::from(estimate.estimated_verifications_remaining),
+            estimate.available_balance.0
+                / (u128::from(estimate.bytes_per_verification) * 10_u128.pow(19))
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Capacity")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "capacity", "events")]
+#[allure_description(
+    "Verifies that a verification emits low_balance_warning only when the available balance is below the threshold."
+)]
+#[allure_test]
+#[test]
+fn test_low_balance_warning() {
+    let mut contract = step("Initialize contract with 10 NEAR", || {
+        set_balance(NearToken::from_near(10));
+        VersionedContract::new(accounts(1))
+    });
+
+    step("No warning above the threshold", || {
+        store(&mut contract, &accounts(2));
+        let event: Option<LowBalanceWarningEvent> = parse_event(&get_logs(), "low_balance_warning");
+        assert!(event.is_none());
+    });
+
+    step("Warning below the threshold", || {
+        set_balance(NearToken::from_near(1));
+        store(&mut contract, &accounts(3));
+        let event: LowBalanceWarningEvent = parse_event(&get_logs(), "low_balance_warning")
+            .expect("low_balance_warning event not found");
+        assert_eq!(event.available_balance, U128(ONE_NEAR + DEPOSIT));
+        assert_eq!(event.threshold, U128(5 * ONE_NEAR));
+        assert!(event.correlation_id.is_some());
+        assert_eq!(event.correlation_id, stored_correlation_id());
+        let bytes_per_verification = contract.get_capacity_estimate().bytes_per_verification;
+        assert_eq!(
+            u128::from(event.estimated_verifications_remaining),
+            (ONE_NEAR + DEPOSIT) / (u128::from(bytes_per_verification) * 10_u128.pow(19))
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Capacity")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "capacity", "admin")]
+#[allure_description(
+    "Verifies that only the backend wallet can set the low balance threshold and that the new value takes effect."
+)]
+#[allure_test]
+#[test]
+fn test_set_low_balance_threshold() {
+    let mut contract = step("Initialize contract with 10 NEAR", || {
+        set_balance(NearToken::from_near(10));
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Non-backend caller is rejected", || {
+        testing_env!(get_context(accounts(2)).build());
+        assert_panic_with(
+            || contract.set_low_balance_threshold(U128(ONE_NEAR)),
+            "ERR_UNAUTHORIZED",
+        );
+    });
+
+    step("Backend raises the threshold", || {
+        set_balance(NearToken::from_near(10));
+        contract.set_low_balance_threshold(U128(20 * ONE_NEAR));
+        assert_eq!(
+            contract.get_config().low_balance_threshold,
+            U128(20 * ONE_NEAR)
+        );
+        assert_eq!(
+            contract.get_capacity_estimate().low_balance_threshold,
+            U128(20 * ONE_NEAR)
+        );
+    });
+
+    step("Writes now warn at the higher threshold", || {
+        store(&mut contract, &accounts(2));
+        let event: LowBalanceWarningEvent = parse_event(&get_logs(), "low_balance_warning")
+            .expect("low_balance_warning event not found");
+        assert_eq!(event.threshold, U128(20 * ONE_NEAR));
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Capacity")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "capacity", "funder")]
+#[allure_description(
+    "Verifies that a low balance asks the registered funder for a top-up at most once per day."
+)]
+#[allure_test]
+#[test]
+fn test_top_up_requests() {
+    const DAY_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+    let mut contract = step("Initialize contract with 1 NEAR", || {
+        set_balance(NearToken::from_near(1));
+        VersionedContract::new(accounts(1))
+    });
+
+    step("No request without a funder", || {
+        store(&mut contract, &accounts(2));
+        let event: Option<TopUpRequestedEvent> = parse_event(&get_logs(), "top_up_requested");
+        assert!(event.is_none());
+    });
+
+    step("Only the backend can set the funder", || {
+        testing_env!(get_context(accounts(2)).build());
+        assert_panic_with(
+            || contract.set_funder(Some(accounts(5))),
+            "ERR_UNAUTHORIZED",
+        );
+        set_balance(NearToken::from_near(1));
+        contract.set_funder(Some(accounts(5)));
+        assert_eq!(contract.get_config().funder, Some(accounts(5)));
+    });
+
+    step(
+        "Low balance requests enough to restore twice the threshold",
+        || {
+            store(&mut contract, &accounts(3));
+            let event: TopUpRequestedEvent = parse_event(&get_logs(), "top_up_requested")
+                .expect("top_up_requested event not found");
+            assert_eq!(event.funder, accounts(5));
+            assert_eq!(event.amount, U128(9 * ONE_NEAR - DEPOSIT));
+            assert!(event.correlation_id.is_some());
+            assert_eq!(event.correlation_id, stored_correlation_id());
+        },
+    );
+
+    step("Requests are rate limited", || {
+        set_balance_at(NearToken::from_near(1), DAY_NS - 1);
+        store(&mut contract, &accounts(4));
+        let event: Option<TopUpRequestedEvent> = parse_event(&get_logs(), "top_up_requested");
+        assert!(event.is_none());
+    });
+
+    step("A day later the funder is asked again", || {
+        testing_env!(get_context(accounts(1))
+            .account_balance(NearToken::from_near(1))
+            .storage_usage(0)
+            .block_timestamp(DAY_NS)
+            .attached_deposit(NearToken::from_millinear(10))
+            .build());
+        contract.request_verification();
+        let event: TopUpRequestedEvent =
+            parse_event(&get_logs(), "top_up_requested").expect("top_up_requested event not found");
+        assert_eq!(event.correlation_id, None);
+    });
+}
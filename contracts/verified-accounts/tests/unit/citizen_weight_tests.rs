@@ -0,0 +1,316 @@
+//! Citizen weight tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, get_context, parse_event,
+    CitizenWeightDelegatedEvent, VerificationStoredEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::json_types::U128;
+use near_sdk::mock::MockAction;
+use near_sdk::test_utils::VMContextBuilder;
+use near_sdk::test_utils::{accounts, get_created_receipts, get_logs};
+use near_sdk::{env, testing_env, AccountId, NearToken, PromiseResult, RuntimeFeesConfig};
+use verified_accounts::VersionedContract;
+
+/// Function calls created in the current context as `(receiver, method, deposit)`
+fn created_function_calls() -> Vec<(AccountId, String, NearToken)> {
+    get_created_receipts()
+        .into_iter()
+        .flat_map(|receipt| {
+            let receiver = receipt.receiver_id.clone();
+            receipt
+                .actions
+                .into_iter()
+                .filter_map(move |action| match action {
+                    MockAction::FunctionCallWeight {
+                        method_name,
+                        attached_deposit,
+                        ..
+                    } => Some((
+                        receiver.clone(),
+                        String::from_utf8(method_name).unwrap(),
+                        attached_deposit,
+                    )),
+                    _ => None,
+                })
+        })
+        .collect()
+}
+
+/// Run the `on_weight_delegated` callback with the outcome of the DAO batch
+fn resolve_delegation(
+    contract: &mut VersionedContract,
+    account_id: AccountId,
+    dao: AccountId,
+    correlation_id: Option<String>,
+    result: PromiseResult,
+) {
+    testing_env!(
+        VMContextBuilder::new()
+            .current_account_id(accounts(0))
+            .predecessor_account_id(accounts(0))
+            .build(),
+        near_sdk::test_vm_config(),
+        RuntimeFeesConfig::test(),
+        Default::default(),
+        vec![result],
+    );
+    contract.on_weight_delegated(account_id, dao, correlation_id);
+}
+
+fn verify(contract: &mut VersionedContract, user: AccountId) {
+    let signer = create_signer(&user);
+    let sig_data =
+        create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+    contract.store_verification(user, sig_data, "ctx".to_string(), None);
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Citizen Weight")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "query", "dao")]
+#[allure_description(
+    "Verifies that each verified account holds one unit of citizen weight, and total supply tracks the verified count."
+)]
+#[allure_test]
+#[test]
+fn test_citizen_weight_follows_verifications() {
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    step("Empty set has zero supply", || {
+        assert_eq!(contract.ft_total_supply(), U128(0));
+        assert_eq!(contract.ft_balance_of(accounts(2)), U128(0));
+    });
+
+    step("Store a verification", || {
+        verify(&mut contract, accounts(2));
+    });
+
+    step("Verified account holds one unit", || {
+        assert_eq!(contract.ft_total_supply(), U128(1));
+        assert_eq!(contract.ft_balance_of(accounts(2)), U128(1));
+        assert_eq!(contract.ft_balance_of(accounts(3)), U128(0));
+    });
+
+    step("No DAO is called without a weight DAO", || {
+        assert!(created_function_calls().is_empty());
+        assert_eq!(contract.get_weight_delegation(accounts(2)), None);
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Citizen Weight")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "admin", "dao")]
+#[allure_description(
+    "Verifies that new verifications register and delegate one unit of weight in the configured SputnikDAO, paying the registration storage, and record the delegation once the DAO accepts it."
+)]
+#[allure_test]
+#[test]
+fn test_verification_delegates_weight_to_dao() {
+    let dao = accounts(4);
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    step("Only the backend can set the weight DAO", || {
+        testing_env!(get_context(accounts(2)).build());
+        assert_panic_with(
+            || contract.set_weight_dao(Some(dao.clone())),
+            "ERR_UNAUTHORIZED",
+        );
+        testing_env!(get_context(accounts(1)).build());
+        contract.set_weight_dao(Some(dao.clone()));
+        assert_eq!(contract.get_config().weight_dao, Some(dao.clone()));
+    });
+
+    let correlation_id = step("Verification registers and delegates in the DAO", || {
+        testing_env!(get_context(accounts(1)).build());
+        verify(&mut contract, accounts(2));
+        let stored: VerificationStoredEvent = parse_event(&get_logs(), "verification_stored")
+            .expect("verification_stored event not found");
+        let callback_args = get_created_receipts()
+            .into_iter()
+            .flat_map(|receipt| receipt.actions)
+            .find_map(|action| match action {
+                MockAction::FunctionCallWeight {
+                    method_name, args, ..
+                } if method_name == b"on_weight_delegated" => Some(args),
+                _ => None,
+            })
+            .expect("on_weight_delegated callback not found");
+        let callback_args: near_sdk::serde_json::Value =
+            near_sdk::serde_json::from_slice(&callback_args).unwrap();
+        assert_eq!(callback_args["correlation_id"], stored.correlation_id);
+
+        let registration_deposit = env::storage_byte_cost().saturating_mul(16);
+        assert_eq!(
+            created_function_calls(),
+            vec![
+                (
+                    dao.clone(),
+                    "register_delegation".to_string(),
+                    registration_deposit
+                ),
+                (
+                    dao.clone(),
+                    "delegate".to_string(),
+                    NearToken::from_yoctonear(0)
+                ),
+                (
+                    accounts(0),
+                    "on_weight_delegated".to_string(),
+                    NearToken::from_yoctonear(0)
+                ),
+            ]
+        );
+        assert_eq!(contract.get_weight_delegation(accounts(2)), None);
+        stored.correlation_id
+    });
+
+    step("The DAO accepts the delegation", || {
+        resolve_delegation(
+            &mut contract,
+            accounts(2),
+            dao.clone(),
+            Some(correlation_id.clone()),
+            PromiseResult::Successful(vec![]),
+        );
+        assert_eq!(
+            contract.get_weight_delegation(accounts(2)),
+            Some(dao.clone())
+        );
+
+        let event: CitizenWeightDelegatedEvent =
+            parse_event(&get_logs(), "citizen_weight_delegated")
+                .expect("citizen_weight_delegated event not found");
+        assert_eq!(event.account_id, accounts(2));
+        assert_eq!(event.dao, dao);
+        assert_eq!(event.correlation_id, Some(correlation_id));
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Citizen Weight")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "admin", "dao")]
+#[allure_description(
+    "Verifies that sync_citizen_weight backfills accounts verified before the DAO was set, skips accounts already delegated, and rejects unverified accounts."
+)]
+#[allure_test]
+#[test]
+fn test_sync_citizen_weight_backfills_once() {
+    let dao = accounts(4);
+    let mut contract = step("Verify an account before the DAO is set", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        let mut contract = VersionedContract::new(backend);
+        verify(&mut contract, accounts(2));
+        contract
+    });
+
+    step("Sync requires a weight DAO", || {
+        testing_env!(get_context(accounts(1)).build());
+        assert_panic_with(
+            || contract.sync_citizen_weight(vec![accounts(2)]),
+            "ERR_WEIGHT_DAO_NOT_CONFIGURED",
+        );
+    });
+
+    step("Sync delegates the existing account", || {
+        testing_env!(get_context(accounts(1)).build());
+        contract.set_weight_dao(Some(dao.clone()));
+        contract.sync_citizen_weight(vec![accounts(2)]);
+        assert_eq!(created_function_calls().len(), 3);
+        resolve_delegation(
+            &mut contract,
+            accounts(2),
+            dao.clone(),
+            None,
+            PromiseResult::Successful(vec![]),
+        );
+        assert_eq!(
+            contract.get_weight_delegation(accounts(2)),
+            Some(dao.clone())
+        );
+    });
+
+    step("A second sync does not delegate again", || {
+        testing_env!(get_context(accounts(1)).build());
+        contract.sync_citizen_weight(vec![accounts(2)]);
+        assert!(created_function_calls().is_empty());
+    });
+
+    step("Unverified accounts and other callers are rejected", || {
+        testing_env!(get_context(accounts(1)).build());
+        assert_panic_with(
+            || contract.sync_citizen_weight(vec![accounts(3)]),
+            "ERR_NOT_VERIFIED",
+        );
+        testing_env!(get_context(accounts(2)).build());
+        assert_panic_with(
+            || contract.sync_citizen_weight(vec![accounts(2)]),
+            "ERR_UNAUTHORIZED",
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Citizen Weight")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "dao")]
+#[allure_description(
+    "Verifies that a delegation rejected by the DAO is not recorded, so sync_citizen_weight sends it again."
+)]
+#[allure_test]
+#[test]
+fn test_rejected_delegation_is_retried_by_sync() {
+    let dao = accounts(4);
+    let mut contract = step("Verify an account with a weight DAO", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        let mut contract = VersionedContract::new(backend);
+        contract.set_weight_dao(Some(dao.clone()));
+        verify(&mut contract, accounts(2));
+        contract
+    });
+
+    step("The DAO rejects the delegation", || {
+        resolve_delegation(
+            &mut contract,
+            accounts(2),
+            dao.clone(),
+            None,
+            PromiseResult::Failed,
+        );
+        assert_eq!(contract.get_weight_delegation(accounts(2)), None);
+        assert!(parse_event::<CitizenWeightDelegatedEvent>(
+            &get_logs(),
+            "citizen_weight_delegated"
+        )
+        .is_none());
+    });
+
+    step("Sync sends the delegation again", || {
+        testing_env!(get_context(accounts(1)).build());
+        contract.sync_citizen_weight(vec![accounts(2)]);
+        let methods: Vec<String> = created_function_calls()
+            .into_iter()
+            .map(|(_, method, _)| method)
+            .collect();
+        assert_eq!(
+            methods,
+            vec!["register_delegation", "delegate", "on_weight_delegated"]
+        );
+    });
+}
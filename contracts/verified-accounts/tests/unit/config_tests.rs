@@ -0,0 +1,67 @@
+//! Config introspection tests for verified-accounts contract
+
+use super::helpers::{get_context, parse_event, ConfigUpdatedEvent};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::testing_env;
+use verified_accounts::{VersionedContract, INTERFACE_VERSION};
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Config")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "config", "view")]
+#[allure_description(
+    "Verifies get_config reports the deployment's tunable parameters and versions."
+)]
+#[allure_test]
+#[test]
+fn test_get_config() {
+    let contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Verify config document", || {
+        let config = contract.get_config();
+        assert_eq!(config.config_version, 1);
+        assert_eq!(config.code_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(config.interface_version, INTERFACE_VERSION);
+        assert_eq!(contract.interface_version(), INTERFACE_VERSION);
+        assert_eq!(config.state_version, contract.get_state_version());
+        assert_eq!(config.record_version, 2);
+        assert_eq!(config.backend_wallet, accounts(1));
+        assert_eq!(config.max_user_context_data_len, 4096);
+        assert_eq!(config.max_batch_size, 100);
+        assert_eq!(config.max_page_size, 100);
+        assert_eq!(config.max_consumers, 10);
+        assert_eq!(config.consumer_notification_tgas, 5);
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Config")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "config", "events")]
+#[allure_description("Verifies that changing the backend wallet emits config_updated and is reflected in get_config.")]
+#[allure_test]
+#[test]
+fn test_config_updated_event_on_backend_wallet_change() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Update backend wallet", || {
+        contract.update_backend_wallet(accounts(2));
+    });
+
+    step("Verify config_updated event and new config", || {
+        let event: ConfigUpdatedEvent =
+            parse_event(&get_logs(), "config_updated").expect("config_updated event not found");
+        assert_eq!(event.field, "backend_wallet");
+        assert_eq!(event.by, accounts(1));
+        assert_eq!(contract.get_config().backend_wallet, accounts(2));
+    });
+}
@@ -0,0 +1,158 @@
+//! Terms version and consent tests for verified-accounts contract
+
+use super::helpers::{assert_panic_with, create_signer, create_valid_signature, get_context};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::accounts;
+use near_sdk::testing_env;
+use near_sdk::AccountId;
+use verified_accounts::{Consent, NearSignatureData, VersionedContract};
+
+const TERMS_V1: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+const TERMS_V2: &str = "2222222222222222222222222222222222222222222222222222222222222222";
+
+/// Signature over a challenge that references `terms_hash`
+fn consenting_signature(user: &AccountId, terms_hash: &str, nonce: u8) -> NearSignatureData {
+    let signer = create_signer(user);
+    let challenge = format!("Identify myself; I accept terms {}", terms_hash);
+    let mut sig_data =
+        create_valid_signature(&signer, user, &challenge, &[nonce; 32], &accounts(0));
+    sig_data.terms_hash = Some(terms_hash.to_string());
+    sig_data
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Consent")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "consent", "legal")]
+#[allure_description(
+    "Verifies that consent to the referenced terms version is recorded and flagged as superseded once newer terms are registered."
+)]
+#[allure_test]
+#[test]
+fn test_consent_recorded_and_superseded() {
+    let mut contract = step("Register the first terms version", || {
+        testing_env!(get_context(accounts(1)).block_timestamp(100).build());
+        let mut contract = VersionedContract::new(accounts(1));
+        contract.register_terms_version(TERMS_V1.to_string());
+        assert_eq!(
+            contract.get_config().current_terms_hash.as_deref(),
+            Some(TERMS_V1)
+        );
+        contract
+    });
+
+    step(
+        "Verification records consent to the referenced terms",
+        || {
+            let user = accounts(2);
+            contract.store_verification(
+                user.clone(),
+                consenting_signature(&user, TERMS_V1, 1),
+                "ctx".to_string(),
+                None,
+            );
+            assert_eq!(
+                contract.get_consent(user),
+                Some(Consent {
+                    terms_hash: TERMS_V1.to_string(),
+                    terms_version: 0,
+                    consented_at: 100,
+                    superseded: false,
+                })
+            );
+        },
+    );
+
+    step("Registering new terms supersedes earlier consent", || {
+        contract.register_terms_version(TERMS_V2.to_string());
+        assert_eq!(contract.get_terms_versions().len(), 2);
+        assert!(contract.get_consent(accounts(2)).unwrap().superseded);
+
+        let user = accounts(3);
+        contract.store_verification(
+            user.clone(),
+            consenting_signature(&user, TERMS_V2, 2),
+            "ctx".to_string(),
+            None,
+        );
+        let consent = contract.get_consent(user).unwrap();
+        assert_eq!(consent.terms_version, 1);
+        assert!(!consent.superseded);
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Consent")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "consent", "validation")]
+#[allure_description(
+    "Verifies that once terms are registered, signatures must reference a registered version inside the signed challenge, and that terms hashes are validated."
+)]
+#[allure_test]
+#[test]
+fn test_consent_enforcement() {
+    let mut contract = step("Register terms", || {
+        testing_env!(get_context(accounts(1)).build());
+        let mut contract = VersionedContract::new(accounts(1));
+        contract.register_terms_version(TERMS_V1.to_string());
+        contract
+    });
+
+    step(
+        "Reject malformed, duplicate and unauthorized registrations",
+        || {
+            assert_panic_with(
+                || contract.register_terms_version("ABC".to_string()),
+                "ERR_INVALID_TERMS_HASH",
+            );
+            assert_panic_with(
+                || contract.register_terms_version(TERMS_V1.to_string()),
+                "ERR_TERMS_ALREADY_REGISTERED",
+            );
+            testing_env!(get_context(accounts(2)).build());
+            assert_panic_with(
+                || contract.register_terms_version(TERMS_V2.to_string()),
+                "ERR_UNAUTHORIZED: Only backend wallet can register terms versions",
+            );
+            testing_env!(get_context(accounts(1)).build());
+        },
+    );
+
+    step("Reject signatures without consent", || {
+        let user = accounts(2);
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        assert_panic_with(
+            || contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None),
+            "ERR_TERMS_NOT_ACCEPTED: Signature must reference a registered terms version",
+        );
+    });
+
+    step("Reject unregistered or unsigned terms references", || {
+        let user = accounts(2);
+        assert_panic_with(
+            || {
+                contract.store_verification(
+                    user.clone(),
+                    consenting_signature(&user, TERMS_V2, 2),
+                    "ctx".to_string(),
+                    None,
+                )
+            },
+            "ERR_TERMS_NOT_ACCEPTED: Terms version",
+        );
+
+        let signer = create_signer(&user);
+        let mut sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[3; 32], &accounts(0));
+        sig_data.terms_hash = Some(TERMS_V1.to_string());
+        assert_panic_with(
+            || contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None),
+            "ERR_TERMS_NOT_ACCEPTED: Signed challenge must reference terms hash",
+        );
+        assert!(!contract.is_verified(user));
+    });
+}
@@ -0,0 +1,272 @@
+//! Consumer registry and notification tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, get_context, parse_event,
+    ConsumerRegisteredEvent, ConsumerUnregisteredEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::mock::MockAction;
+use near_sdk::test_utils::{accounts, get_created_receipts, get_logs};
+use near_sdk::testing_env;
+use near_sdk::AccountId;
+use verified_accounts::{VerificationStatus, VersionedContract};
+
+/// Collect (receiver, method, args) of every function call receipt created so far
+fn created_function_calls() -> Vec<(AccountId, String, String)> {
+    get_created_receipts()
+        .into_iter()
+        .flat_map(|receipt| {
+            let receiver = receipt.receiver_id.clone();
+            receipt
+                .actions
+                .into_iter()
+                .filter_map(move |action| match action {
+                    MockAction::FunctionCallWeight {
+                        method_name, args, ..
+                    } => Some((
+                        receiver.clone(),
+                        String::from_utf8(method_name).unwrap(),
+                        String::from_utf8(args).unwrap(),
+                    )),
+                    _ => None,
+                })
+        })
+        .collect()
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Consumers")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "admin", "consumers")]
+#[allure_description(
+    "Verifies that the backend wallet can register and unregister consumer contracts, emitting events."
+)]
+#[allure_test]
+#[test]
+fn test_register_and_unregister_consumer() {
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    step("Register consumer and verify event", || {
+        contract.register_consumer(accounts(3));
+        assert_eq!(contract.get_consumers(), vec![accounts(3)]);
+
+        let event: ConsumerRegisteredEvent = parse_event(&get_logs(), "consumer_registered")
+            .expect("consumer_registered event not found");
+        assert_eq!(event.contract_id, accounts(3));
+    });
+
+    step("Unregister consumer and verify event", || {
+        testing_env!(get_context(accounts(1)).build());
+        contract.unregister_consumer(accounts(3));
+        assert!(contract.get_consumers().is_empty());
+
+        let event: ConsumerUnregisteredEvent = parse_event(&get_logs(), "consumer_unregistered")
+            .expect("consumer_unregistered event not found");
+        assert_eq!(event.contract_id, accounts(3));
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Consumers")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "security", "authorization", "consumers")]
+#[allure_description("Verifies that only the backend wallet can manage the consumer registry.")]
+#[allure_test]
+#[test]
+fn test_unauthorized_consumer_registration() {
+    let mut contract = step("Initialize contract with unauthorized caller", || {
+        testing_env!(get_context(accounts(0)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Attempt to register consumer", || {
+        assert_panic_with(
+            || contract.register_consumer(accounts(3)),
+            "Only backend wallet can register consumers",
+        );
+    });
+
+    step("Attempt to unregister consumer", || {
+        assert_panic_with(
+            || contract.unregister_consumer(accounts(3)),
+            "Only backend wallet can unregister consumers",
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Consumers")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "validation", "consumers")]
+#[allure_description(
+    "Verifies duplicate registrations, unknown removals, and the consumer limit are rejected."
+)]
+#[allure_test]
+#[test]
+fn test_consumer_registry_limits() {
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    step("Reject duplicate registration", || {
+        contract.register_consumer(accounts(3));
+        assert_panic_with(
+            || contract.register_consumer(accounts(3)),
+            "Consumer already registered",
+        );
+    });
+
+    step("Reject removing unknown consumer", || {
+        assert_panic_with(
+            || contract.unregister_consumer(accounts(4)),
+            "Consumer not registered",
+        );
+    });
+
+    step("Reject registration beyond the limit", || {
+        for i in 1..10 {
+            contract.register_consumer(format!("consumer{}.near", i).parse().unwrap());
+        }
+        assert_eq!(contract.get_consumers().len(), 10);
+        assert_panic_with(
+            || contract.register_consumer("one-too-many.near".parse().unwrap()),
+            "Consumer limit of 10 reached",
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Consumers")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "consumers", "cross-contract")]
+#[allure_description(
+    "Verifies that storing a verification pushes on_verification_changed, with the verification's correlation ID, to every registered consumer."
+)]
+#[allure_test]
+#[test]
+fn test_store_verification_notifies_consumers() {
+    let (mut contract, user) = step("Initialize contract with two consumers", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        let mut contract = VersionedContract::new(backend);
+        contract.register_consumer(accounts(3));
+        contract.register_consumer(accounts(4));
+        (contract, accounts(2))
+    });
+
+    let correlation_id = step("Store verification", || {
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        let correlation_id = sig_data.correlation_id();
+        contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
+        correlation_id
+    });
+
+    step("Verify one notification per consumer", || {
+        let calls = created_function_calls();
+        assert_eq!(calls.len(), 2);
+
+        let expected_args = near_sdk::serde_json::json!({
+            "account_id": user,
+            "status": VerificationStatus::Verified,
+            "correlation_id": correlation_id,
+        });
+        for (consumer, (receiver, method, args)) in
+            [accounts(3), accounts(4)].iter().zip(calls.iter())
+        {
+            assert_eq!(receiver, consumer);
+            assert_eq!(method, "on_verification_changed");
+            let args: near_sdk::serde_json::Value = near_sdk::serde_json::from_str(args).unwrap();
+            assert_eq!(args, expected_args);
+        }
+    });
+
+    step(
+        "Two-argument consumers still parse the notification",
+        || {
+            #[derive(near_sdk::serde::Deserialize)]
+            #[serde(crate = "near_sdk::serde")]
+            struct LegacyArgs {
+                account_id: AccountId,
+                status: VerificationStatus,
+            }
+            let (_, _, args) = &created_function_calls()[0];
+            let legacy: LegacyArgs = near_sdk::serde_json::from_str(args).unwrap();
+            assert_eq!(legacy.account_id, user);
+            assert_eq!(legacy.status, VerificationStatus::Verified);
+        },
+    );
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Consumers")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "consumers", "cross-contract")]
+#[allure_description(
+    "Verifies that a completion callback passed to store_verification is called with the verified account, and that oversized method names are rejected."
+)]
+#[allure_test]
+#[test]
+fn test_store_verification_completion_callback() {
+    let (mut contract, user) = step("Initialize contract without consumers", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        (VersionedContract::new(backend), accounts(2))
+    });
+
+    step("Reject an oversized callback method name", || {
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        assert_panic_with(
+            || {
+                contract.store_verification(
+                    user.clone(),
+                    sig_data,
+                    "ctx".to_string(),
+                    Some((accounts(3), "m".repeat(257))),
+                )
+            },
+            "ERR_INPUT_TOO_LONG: Callback method name exceeds maximum length of 256",
+        );
+    });
+
+    let correlation_id = step("Store verification with a callback", || {
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        let correlation_id = sig_data.correlation_id();
+        contract.store_verification(
+            user.clone(),
+            sig_data,
+            "ctx".to_string(),
+            Some((accounts(3), "on_citizen_verified".to_string())),
+        );
+        correlation_id
+    });
+
+    step("Verify the callback receipt", || {
+        let calls = created_function_calls();
+        assert_eq!(calls.len(), 1);
+        let (receiver, method, args) = &calls[0];
+        assert_eq!(receiver, &accounts(3));
+        assert_eq!(method, "on_citizen_verified");
+        let args: near_sdk::serde_json::Value = near_sdk::serde_json::from_str(args).unwrap();
+        assert_eq!(
+            args,
+            near_sdk::serde_json::json!({ "account_id": user, "correlation_id": correlation_id })
+        );
+    });
+}
@@ -0,0 +1,139 @@
+//! Hash-only storage mode tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, get_context, parse_event,
+    ConfigUpdatedEvent, ProofDataPrunedEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::testing_env;
+use verified_accounts::interface::VerificationV2;
+use verified_accounts::{ContextStorage, VersionedContract};
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Data Minimization")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "privacy", "storage")]
+#[allure_description(
+    "Verifies that hash-only mode stores the SHA-256 of user_context_data instead of the data, flags the record, and leaves earlier records untouched."
+)]
+#[allure_test]
+#[test]
+fn test_hash_only_storage_mode() {
+    let backend = accounts(1);
+    let mut contract = step("Store one record in full mode", || {
+        testing_env!(get_context(backend.clone()).block_timestamp(100).build());
+        let mut contract = VersionedContract::new(backend.clone());
+        let user = accounts(2);
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        contract.store_verification(user, sig_data, "full ctx".to_string(), None);
+        contract
+    });
+
+    step("Enable hash-only storage", || {
+        contract.set_hash_only_storage(true);
+        let event: ConfigUpdatedEvent =
+            parse_event(&get_logs(), "config_updated").expect("config_updated event not found");
+        assert_eq!(event.field, "hash_only_storage");
+        assert!(contract.get_config().hash_only_storage);
+    });
+
+    step("New records keep only the hash", || {
+        let user = accounts(3);
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[2; 32], &accounts(0));
+        contract.store_verification(user.clone(), sig_data, "private ctx".to_string(), None);
+
+        let record = contract.get_full_verification(user.clone()).unwrap();
+        assert_eq!(record.context_storage, ContextStorage::HashOnly);
+        assert!(record.user_context_data.is_empty());
+        assert_eq!(
+            record.user_context_hash,
+            VerificationV2::hash_context("private ctx")
+        );
+        assert!(contract.verify_record_integrity(user).issues.is_empty());
+    });
+
+    step("Earlier records are unchanged", || {
+        let record = contract.get_full_verification(accounts(2)).unwrap();
+        assert_eq!(record.context_storage, ContextStorage::Full);
+        assert_eq!(record.user_context_data, "full ctx");
+        assert_eq!(
+            record.user_context_hash,
+            VerificationV2::hash_context("full ctx")
+        );
+        assert!(contract
+            .verify_record_integrity(accounts(2))
+            .issues
+            .is_empty());
+    });
+
+    step("Only the backend can change the mode", || {
+        testing_env!(get_context(accounts(3)).build());
+        assert_panic_with(
+            || contract.set_hash_only_storage(false),
+            "ERR_UNAUTHORIZED: Only backend wallet can set storage mode",
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Data Minimization")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "privacy", "storage")]
+#[allure_description(
+    "Verifies that a verified account can delete its own context data while keeping its hash, timestamp and verified status."
+)]
+#[allure_test]
+#[test]
+fn test_prune_my_proof_data() {
+    let user = accounts(2);
+    let mut contract = step("Store a full record", || {
+        testing_env!(get_context(accounts(1)).block_timestamp(100).build());
+        let mut contract = VersionedContract::new(accounts(1));
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        contract.store_verification(user.clone(), sig_data, "full ctx".to_string(), None);
+        contract
+    });
+
+    step("Unverified accounts cannot prune", || {
+        testing_env!(get_context(accounts(3)).build());
+        assert_panic_with(
+            || contract.prune_my_proof_data(),
+            "ERR_NOT_VERIFIED: NEAR account is not verified",
+        );
+    });
+
+    step("The account prunes its own data", || {
+        testing_env!(get_context(user.clone()).block_timestamp(200).build());
+        contract.prune_my_proof_data();
+        let event: ProofDataPrunedEvent = parse_event(&get_logs(), "proof_data_pruned")
+            .expect("proof_data_pruned event not found");
+        assert_eq!(event.account_id, user);
+
+        let record = contract.get_full_verification(user.clone()).unwrap();
+        assert_eq!(record.context_storage, ContextStorage::Pruned);
+        assert!(record.user_context_data.is_empty());
+        assert_eq!(record.verified_at, 100);
+        assert_eq!(
+            record.user_context_hash,
+            VerificationV2::hash_context("full ctx")
+        );
+        assert!(contract.is_verified(user.clone()));
+        assert!(contract
+            .verify_record_integrity(user.clone())
+            .issues
+            .is_empty());
+    });
+
+    step("Pruning twice is rejected", || {
+        assert_panic_with(|| contract.prune_my_proof_data(), "ERR_NOTHING_TO_PRUNE");
+    });
+}
@@ -0,0 +1,97 @@
+//! Error code tests for verified-accounts contract
+
+use super::helpers::{assert_panic_with, create_test_sig_data, get_context};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::accounts;
+use near_sdk::testing_env;
+use near_sdk::NearToken;
+use std::collections::HashSet;
+use verified_accounts::{ErrorCode, VersionedContract};
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Error Codes")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "errors", "catalog")]
+#[allure_description(
+    "Verifies the error catalog lists every code once, with stable string codes matching their JSON form."
+)]
+#[allure_test]
+#[test]
+fn test_error_catalog_is_complete_and_stable() {
+    let contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Verify catalog covers all codes exactly once", || {
+        let catalog = contract.get_error_catalog();
+        assert_eq!(catalog.len(), ErrorCode::ALL.len());
+
+        let codes: HashSet<&str> = catalog.iter().map(|e| e.code.as_str()).collect();
+        assert_eq!(codes.len(), catalog.len(), "Error codes must be unique");
+        assert!(catalog.iter().all(|e| !e.description.is_empty()));
+    });
+
+    step("Verify JSON representation matches as_str", || {
+        for code in ErrorCode::ALL {
+            let json = near_sdk::serde_json::to_string(&code).unwrap();
+            assert_eq!(json, format!("\"{}\"", code.as_str()));
+            assert!(code.as_str().starts_with("ERR_"));
+        }
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Error Codes")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "errors")]
+#[allure_description("Verifies that panics are prefixed with their error code.")]
+#[allure_test]
+#[test]
+fn test_panics_are_prefixed_with_error_code() {
+    let mut contract = step("Initialize contract with unauthorized caller", || {
+        testing_env!(get_context(accounts(0)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Unauthorized pause reports ERR_UNAUTHORIZED", || {
+        assert_panic_with(
+            || contract.pause(),
+            "ERR_UNAUTHORIZED: Only backend wallet can pause contract",
+        );
+    });
+
+    step("Missing deposit reports ERR_DEPOSIT_REQUIRED", || {
+        let mut context = get_context(accounts(1));
+        context.attached_deposit(NearToken::from_yoctonear(0));
+        testing_env!(context.build());
+        assert_panic_with(
+            || contract.pause(),
+            "ERR_DEPOSIT_REQUIRED: Requires attached deposit of exactly 1 yoctoNEAR",
+        );
+    });
+
+    step(
+        "Mismatched signer reports ERR_SIGNATURE_ACCOUNT_MISMATCH",
+        || {
+            testing_env!(get_context(accounts(1)).build());
+            let sig_data = create_test_sig_data(accounts(3));
+            assert_panic_with(
+                || contract.store_verification(accounts(2), sig_data, "ctx".to_string(), None),
+                "ERR_SIGNATURE_ACCOUNT_MISMATCH: Signature account ID must match near_account_id",
+            );
+        },
+    );
+
+    step("Oversized batch reports ERR_BATCH_TOO_LARGE", || {
+        let ids = vec![accounts(2); 101];
+        assert_panic_with(
+            || {
+                contract.are_verified(ids);
+            },
+            "ERR_BATCH_TOO_LARGE",
+        );
+    });
+}
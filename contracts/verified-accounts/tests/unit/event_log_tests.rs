@@ -0,0 +1,71 @@
+//! On-chain event log tests for verified-accounts contract
+
+use super::helpers::{create_signer, create_valid_signature, get_context};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::testing_env;
+use verified_accounts::VersionedContract;
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Event Log")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "query", "events")]
+#[allure_description(
+    "Verifies that every emitted event is appended to the event log with a gapless seq and the hash of its log line."
+)]
+#[allure_test]
+#[test]
+fn test_events_are_logged_in_sequence() {
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    step("Empty log returns nothing", || {
+        assert!(contract.get_events(0, 10).is_empty());
+    });
+
+    let logs = step("Pause, unpause and store a verification", || {
+        contract.pause();
+        contract.unpause();
+        let user = accounts(2);
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        contract.store_verification(user, sig_data, "ctx".to_string(), None);
+        get_logs()
+    });
+
+    step("Verify logged events", || {
+        let events = contract.get_events(0, 10);
+        let kinds: Vec<&str> = events.iter().map(|e| e.kind.as_str()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "contract_paused",
+                "contract_unpaused",
+                "verification_stored"
+            ]
+        );
+        let seqs: Vec<u64> = events.iter().map(|e| e.seq).collect();
+        assert_eq!(seqs, vec![0, 1, 2]);
+        // No log line bypasses the event log
+        assert_eq!(logs.len(), events.len());
+
+        let stored_log = logs
+            .iter()
+            .find(|log| log.contains("\"event\":\"verification_stored\""))
+            .expect("verification_stored log not found");
+        let expected_hash: [u8; 32] = near_sdk::env::sha256_array(stored_log.as_bytes());
+        assert_eq!(<[u8; 32]>::from(events[2].payload_hash), expected_hash);
+    });
+
+    step("Page from a later seq", || {
+        let events = contract.get_events(1, 1);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, "contract_unpaused");
+        assert!(contract.get_events(3, 10).is_empty());
+    });
+}
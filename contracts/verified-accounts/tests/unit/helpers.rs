@@ -9,7 +9,12 @@ use verified_accounts::NearSignatureData;
 
 // Re-export event structs from the contract for test use
 pub use verified_accounts::{
-    BackendWalletUpdatedEvent, ContractPausedEvent, ContractUnpausedEvent, VerificationStoredEvent,
+    BackendRotationCancelledEvent, BackendRotationStartedEvent, BackendWalletUpdatedEvent,
+    BatchExecutedEvent, CitizenWeightDelegatedEvent, ConfigUpdatedEvent, ConsumerRegisteredEvent,
+    ConsumerUnregisteredEvent, ContractPausedEvent, ContractUnpausedEvent, LinkedKeyRotatedEvent,
+    LowBalanceWarningEvent, PermissionGrantedEvent, PermissionRevokedEvent, ProfileUpdatedEvent,
+    ProofDataPrunedEvent, ReferralRecordedEvent, TopUpRequestedEvent, VerificationRequestedEvent,
+    VerificationRequestsRemovedEvent, VerificationStoredEvent,
 };
 
 /// Create a test context with the given predecessor account
@@ -57,6 +62,9 @@ pub fn create_test_sig_data(user: AccountId) -> verified_accounts::NearSignature
         challenge: "Identify myself".to_string(),
         nonce: Base64VecU8::from(vec![0; 32]),
         recipient: env::current_account_id(),
+        app_id: None,
+        terms_hash: None,
+        referred_by: None,
     }
 }
 
@@ -72,6 +80,18 @@ pub fn create_valid_signature(
     challenge: &str,
     nonce: &[u8],
     recipient: &AccountId,
+) -> NearSignatureData {
+    create_valid_signature_for_app(signer, signer_id, challenge, nonce, recipient, None)
+}
+
+/// Produce a valid NEP-413 signature bound to `app_id` (signed message is `"[app_id] challenge"`)
+pub fn create_valid_signature_for_app(
+    signer: &Signer,
+    signer_id: &AccountId,
+    challenge: &str,
+    nonce: &[u8],
+    recipient: &AccountId,
+    app_id: Option<&str>,
 ) -> NearSignatureData {
     // Step 1: Serialize the NEP-413 prefix tag (2^31 + 413)
     let tag: u32 = 2_147_484_061;
@@ -81,8 +101,12 @@ pub fn create_valid_signature(
     let mut nonce_array = [0u8; 32];
     nonce_array.copy_from_slice(nonce);
 
+    let message = match app_id {
+        Some(app_id) => format!("[{}] {}", app_id, challenge),
+        None => challenge.to_string(),
+    };
     let payload = verified_accounts::Nep413Payload {
-        message: challenge.to_string(),
+        message,
         nonce: nonce_array,
         recipient: recipient.to_string(),
         callback_url: None,
@@ -111,6 +135,9 @@ pub fn create_valid_signature(
         challenge: challenge.to_string(),
         nonce: Base64VecU8::from(nonce.to_vec()),
         recipient: recipient.clone(),
+        app_id: app_id.map(str::to_string),
+        terms_hash: None,
+        referred_by: None,
     }
 }
 
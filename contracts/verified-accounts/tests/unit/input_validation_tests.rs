@@ -37,12 +37,16 @@ fn test_account_id_mismatch() {
                     challenge: "Identify myself".to_string(),
                     nonce: vec![0; 32].into(),
                     recipient: accounts(0),
+                    app_id: None,
+                    terms_hash: None,
+                    referred_by: None,
                 };
 
                 contract.store_verification(
                     user, // But we're trying to verify accounts(2)
                     sig_data,
                     "test_user_context_data".to_string(),
+                    None,
                 );
             },
             "Signature account ID must match near_account_id",
@@ -81,12 +85,16 @@ fn test_recipient_mismatch() {
                     challenge: "Identify myself".to_string(),
                     nonce: vec![0; 32].into(),
                     recipient: different_recipient, // Mismatch: recipient is accounts(3)
+                    app_id: None,
+                    terms_hash: None,
+                    referred_by: None,
                 };
 
                 contract.store_verification(
                     user, // But we're trying to verify accounts(2)
                     sig_data,
                     "test_user_context_data".to_string(),
+                    None,
                 );
             },
             "Signature recipient must match contract account",
@@ -193,11 +201,14 @@ fn test_user_context_data_too_long() {
                         challenge: "Identify myself".to_string(),
                         nonce: vec![0; 32].into(),
                         recipient: accounts(0),
+                        app_id: None,
+                        terms_hash: None,
+                        referred_by: None,
                     };
 
                     let too_long_user_context = "x".repeat(4097);
 
-                    contract.store_verification(user, sig_data, too_long_user_context);
+                    contract.store_verification(user, sig_data, too_long_user_context, None);
                 },
                 "User context data exceeds maximum length of 4096",
             );
@@ -230,7 +241,7 @@ fn test_user_context_data_max_length_allowed() {
         "Store verification with 4096-char user_context_data",
         || {
             let context_data = "c".repeat(4096);
-            contract.store_verification(user.clone(), sig_data, context_data);
+            contract.store_verification(user.clone(), sig_data, context_data, None);
         },
     );
 
@@ -0,0 +1,86 @@
+//! Record integrity check tests for verified-accounts contract
+
+use super::helpers::get_context;
+use allure_rs::prelude::*;
+use near_sdk::store::IterableMap;
+use near_sdk::test_utils::accounts;
+use near_sdk::testing_env;
+use near_sdk::AccountId;
+use verified_accounts::interface::VerificationV1;
+use verified_accounts::{
+    ContractV1, IntegrityIssue, StorageKey, VersionedContract, VersionedVerification,
+};
+
+fn record(account_id: AccountId, verified_at: u64, context_len: usize) -> VersionedVerification {
+    VersionedVerification::V1(VerificationV1 {
+        near_account_id: account_id,
+        verified_at,
+        user_context_data: "x".repeat(context_len),
+    })
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Integrity")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "audit", "migration")]
+#[allure_description(
+    "Verifies that integrity views report clean records as empty and flag each kind of corruption."
+)]
+#[allure_test]
+#[test]
+fn test_record_integrity_reports() {
+    let contract = step("Build state with clean and corrupted records", || {
+        testing_env!(get_context(accounts(1)).block_timestamp(1_000).build());
+        let mut verifications = IterableMap::new(StorageKey::Accounts);
+        verifications.insert(accounts(2), record(accounts(2), 500, 10));
+        verifications.insert(accounts(3), record(accounts(4), 500, 10));
+        verifications.insert(accounts(4), record(accounts(4), 500, 5000));
+        verifications.insert(accounts(5), record(accounts(5), 2_000, 10));
+        VersionedContract::V1(ContractV1 {
+            backend_wallet: accounts(1),
+            verifications,
+            paused: false,
+        })
+    });
+
+    step("Single-record checks", || {
+        assert!(contract
+            .verify_record_integrity(accounts(2))
+            .issues
+            .is_empty());
+        assert_eq!(
+            contract.verify_record_integrity(accounts(3)).issues,
+            vec![IntegrityIssue::AccountIdMismatch {
+                stored: accounts(4)
+            }]
+        );
+        assert_eq!(
+            contract.verify_record_integrity(accounts(4)).issues,
+            vec![IntegrityIssue::ContextDataTooLong { len: 5000 }]
+        );
+        assert_eq!(
+            contract.verify_record_integrity(accounts(5)).issues,
+            vec![IntegrityIssue::InvalidTimestamp { verified_at: 2_000 }]
+        );
+        assert_eq!(
+            contract
+                .verify_record_integrity("missing.near".parse().unwrap())
+                .issues,
+            vec![IntegrityIssue::NotFound]
+        );
+    });
+
+    step("Paged sweep reports every record in order", || {
+        let reports = contract.verify_records_integrity(0, 100);
+        let ids: Vec<AccountId> = reports.iter().map(|r| r.account_id.clone()).collect();
+        assert_eq!(
+            ids,
+            vec![accounts(2), accounts(3), accounts(4), accounts(5)]
+        );
+        let flagged = reports.iter().filter(|r| !r.issues.is_empty()).count();
+        assert_eq!(flagged, 3);
+
+        assert_eq!(contract.verify_records_integrity(3, 100).len(), 1);
+    });
+}
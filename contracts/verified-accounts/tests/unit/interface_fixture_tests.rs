@@ -0,0 +1,257 @@
+//! Fixture round-trip tests for verified-accounts interface types
+//!
+//! Each JSON fixture in `tests/fixtures/responses` is the response of a real view call on
+//! a contract built by `fixture_contract`, and must deserialize into its interface type
+//! and serialize back to the same document, so schema drift fails here instead of in
+//! consumers. After an intended response change, regenerate them with:
+//!
+//! `UPDATE_FIXTURES=1 cargo test --features testing --test unit interface_fixture`
+
+use super::helpers::{create_signer, create_valid_signature, get_context};
+use allure_rs::prelude::*;
+use near_sdk::serde::de::DeserializeOwned;
+use near_sdk::serde::Serialize;
+use near_sdk::serde_json::Value;
+use near_sdk::testing_env;
+use verified_accounts::interface::*;
+use verified_accounts::VersionedContract;
+
+/// Verification timestamp of `alice.testnet`; `bob.testnet` follows a minute later
+const FIXTURE_VERIFIED_AT: u64 = 1_700_000_000_000_000_000;
+
+/// Block height of the first fixture verification
+const FIXTURE_BLOCK_HEIGHT: u64 = 180_000_000;
+
+/// Build a contract with `alice.testnet` and `bob.testnet` verified a minute apart
+fn fixture_contract() -> VersionedContract {
+    let backend = near_sdk::test_utils::accounts(1);
+    testing_env!(get_context(backend.clone()).build());
+    let mut contract = VersionedContract::new(backend.clone());
+
+    let users = [
+        (
+            "alice.testnet",
+            r#"{"accountId":"alice.testnet","nonce":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="}"#,
+        ),
+        ("bob.testnet", "ctx"),
+    ];
+    for (i, (user, context)) in (0u64..).zip(users) {
+        testing_env!(get_context(backend.clone())
+            .block_height(FIXTURE_BLOCK_HEIGHT + i * 60)
+            .block_timestamp(FIXTURE_VERIFIED_AT + i * 60_000_000_000)
+            .build());
+        let user: near_sdk::AccountId = user.parse().unwrap();
+        let signer = create_signer(&user);
+        let nonce = [i as u8; 32];
+        let sig_data = create_valid_signature(
+            &signer,
+            &user,
+            "Identify myself",
+            &nonce,
+            &near_sdk::test_utils::accounts(0),
+        );
+        contract.store_verification(user, sig_data, context.to_string(), None);
+    }
+    contract
+}
+
+/// Assert `fixture` is exactly the pretty-printed `response`, or rewrite it when
+/// `UPDATE_FIXTURES` is set
+fn assert_fixture_current<T: Serialize>(name: &str, fixture: &str, response: &T) {
+    let generated = near_sdk::serde_json::to_string_pretty(response).unwrap() + "\n";
+    if std::env::var_os("UPDATE_FIXTURES").is_some() {
+        let path = format!(
+            "{}/tests/fixtures/responses/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        );
+        std::fs::write(&path, &generated).unwrap();
+        return;
+    }
+    assert_eq!(
+        fixture, generated,
+        "{} is stale; regenerate it with UPDATE_FIXTURES=1",
+        name
+    );
+}
+
+/// Deserialize `fixture` as `T`, re-serialize it, and assert the JSON is unchanged
+fn assert_json_roundtrip<T: DeserializeOwned + Serialize>(name: &str, fixture: &str) -> T {
+    let expected: Value = near_sdk::serde_json::from_str(fixture)
+        .unwrap_or_else(|e| panic!("{} is not valid JSON: {}", name, e));
+    let parsed: T = near_sdk::serde_json::from_str(fixture)
+        .unwrap_or_else(|e| panic!("{} no longer matches its type: {}", name, e));
+    let actual = near_sdk::serde_json::to_value(&parsed).unwrap();
+    assert_eq!(actual, expected, "{} does not round-trip", name);
+    parsed
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    let hex = hex.trim();
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Interface Tests")]
+#[allure_sub_suite("Fixtures")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "serialization", "json", "fixtures")]
+#[allure_description(
+    "Verifies that each JSON fixture is the current response of its view method on a contract with two verified accounts."
+)]
+#[allure_test]
+#[test]
+fn test_view_response_fixtures_are_current() {
+    let empty = step("Read statistics of an empty contract", || {
+        testing_env!(get_context(near_sdk::test_utils::accounts(1)).build());
+        VersionedContract::new(near_sdk::test_utils::accounts(1)).get_statistics()
+    });
+    assert_fixture_current(
+        "get_statistics_empty.json",
+        include_str!("../fixtures/responses/get_statistics_empty.json"),
+        &empty,
+    );
+
+    let contract = step("Verify alice.testnet and bob.testnet", fixture_contract);
+
+    step("Compare view responses with the fixtures", || {
+        let alice: near_sdk::AccountId = "alice.testnet".parse().unwrap();
+        assert_fixture_current(
+            "get_full_verification.json",
+            include_str!("../fixtures/responses/get_full_verification.json"),
+            &contract.get_full_verification(alice.clone()),
+        );
+        assert_fixture_current(
+            "get_verification.json",
+            include_str!("../fixtures/responses/get_verification.json"),
+            &contract.get_verification(alice),
+        );
+        assert_fixture_current(
+            "get_statistics.json",
+            include_str!("../fixtures/responses/get_statistics.json"),
+            &contract.get_statistics(),
+        );
+        assert_fixture_current(
+            "list_verifications.json",
+            include_str!("../fixtures/responses/list_verifications.json"),
+            &contract.list_verifications(0, 1),
+        );
+        assert_fixture_current(
+            "get_membership_diffs.json",
+            include_str!("../fixtures/responses/get_membership_diffs.json"),
+            &contract.get_membership_diffs(0, 10),
+        );
+        assert_fixture_current(
+            "get_events.json",
+            include_str!("../fixtures/responses/get_events.json"),
+            &contract.get_events(0, 10),
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Interface Tests")]
+#[allure_sub_suite("Fixtures")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "serialization", "json", "fixtures")]
+#[allure_description(
+    "Verifies that each JSON fixture deserializes into its interface type and serializes back to the same document."
+)]
+#[allure_test]
+#[test]
+fn test_view_response_fixtures_roundtrip() {
+    let verification: Verification = assert_json_roundtrip(
+        "get_full_verification.json",
+        include_str!("../fixtures/responses/get_full_verification.json"),
+    );
+    assert_eq!(verification.near_account_id.as_str(), "alice.testnet");
+
+    let summary: VerificationSummary = assert_json_roundtrip(
+        "get_verification.json",
+        include_str!("../fixtures/responses/get_verification.json"),
+    );
+    assert_eq!(summary.verified_at, verification.verified_at);
+
+    let stats: VerificationStatistics = assert_json_roundtrip(
+        "get_statistics.json",
+        include_str!("../fixtures/responses/get_statistics.json"),
+    );
+    assert_eq!(stats.verified_count, 2);
+    assert_eq!(stats.record_version, CURRENT_VERIFICATION_VERSION);
+
+    let empty: VerificationStatistics = assert_json_roundtrip(
+        "get_statistics_empty.json",
+        include_str!("../fixtures/responses/get_statistics_empty.json"),
+    );
+    assert_eq!(empty.first_verified_at, None);
+
+    let page: Page<Verification> = assert_json_roundtrip(
+        "list_verifications.json",
+        include_str!("../fixtures/responses/list_verifications.json"),
+    );
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.total, 2);
+    assert_eq!(page.next_index, Some(1));
+
+    let diffs: Vec<MembershipDiff> = assert_json_roundtrip(
+        "get_membership_diffs.json",
+        include_str!("../fixtures/responses/get_membership_diffs.json"),
+    );
+    assert_eq!(diffs.len(), 2);
+    assert_eq!(diffs[1].change, MembershipChange::Added);
+
+    let events: Vec<IndexedEvent> = assert_json_roundtrip(
+        "get_events.json",
+        include_str!("../fixtures/responses/get_events.json"),
+    );
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].kind, "verification_stored");
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Interface Tests")]
+#[allure_sub_suite("Fixtures")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "serialization", "borsh", "fixtures")]
+#[allure_description(
+    "Verifies that stored V1 and V2 record fixtures still decode with the current Borsh layout and re-encode to the same bytes."
+)]
+#[allure_test]
+#[test]
+fn test_stored_record_fixture_roundtrip() {
+    let bytes = decode_hex(include_str!(
+        "../fixtures/responses/versioned_verification_v1.borsh.hex"
+    ));
+
+    let record: VersionedVerification = near_sdk::borsh::from_slice(&bytes)
+        .expect("stored V1 record no longer decodes; Borsh layout changed");
+    let verification = record.clone().into_current();
+    assert_eq!(verification.near_account_id.as_str(), "alice.testnet");
+    assert_eq!(verification.verified_at, 1_700_000_000_000_000_000);
+    assert_eq!(verification.user_context_data, "ctx");
+    assert_eq!(verification.context_storage, ContextStorage::Full);
+    assert_eq!(
+        verification.user_context_hash,
+        VerificationV2::hash_context("ctx")
+    );
+
+    assert_eq!(near_sdk::borsh::to_vec(&record).unwrap(), bytes);
+
+    let bytes = decode_hex(include_str!(
+        "../fixtures/responses/versioned_verification_v2.borsh.hex"
+    ));
+    let record: VersionedVerification = near_sdk::borsh::from_slice(&bytes)
+        .expect("stored V2 record no longer decodes; Borsh layout changed");
+    assert_eq!(record.version(), 2);
+    let verification = record.as_current();
+    assert_eq!(verification.context_storage, ContextStorage::HashOnly);
+    assert!(verification.user_context_data.is_empty());
+    assert_eq!(
+        verification.user_context_hash,
+        VerificationV2::hash_context("ctx")
+    );
+    assert_eq!(near_sdk::borsh::to_vec(&record).unwrap(), bytes);
+}
@@ -38,6 +38,8 @@ fn test_verification_serialization() {
         near_account_id: "test.near".parse().unwrap(),
         verified_at: 1234567890,
         user_context_data: "context".to_string(),
+        user_context_hash: VerificationV2::hash_context("context"),
+        context_storage: ContextStorage::Full,
     };
 
     // Test Borsh serialization
@@ -78,6 +80,8 @@ fn test_verification_json_roundtrip() {
         near_account_id: "user.near".parse().unwrap(),
         verified_at: 1700000000000000000,
         user_context_data: "test context data".to_string(),
+        user_context_hash: VerificationV2::hash_context("test context data"),
+        context_storage: ContextStorage::Full,
     };
 
     let json = near_sdk::serde_json::to_string(&verification).unwrap();
@@ -85,6 +89,8 @@ fn test_verification_json_roundtrip() {
     assert_eq!(decoded.near_account_id, verification.near_account_id);
     assert_eq!(decoded.verified_at, verification.verified_at);
     assert_eq!(decoded.user_context_data, verification.user_context_data);
+    assert_eq!(decoded.user_context_hash, verification.user_context_hash);
+    assert!(json.contains("\"context_storage\":\"full\""));
 }
 
 #[allure_parent_suite("Near Citizens House")]
@@ -99,9 +105,12 @@ fn test_empty_user_context_data() {
         near_account_id: "test.near".parse().unwrap(),
         verified_at: 0,
         user_context_data: "".to_string(), // Empty context
+        user_context_hash: VerificationV2::hash_context("hashed context"),
+        context_storage: ContextStorage::HashOnly,
     };
 
     let json = near_sdk::serde_json::to_string(&verification).unwrap();
     let decoded: Verification = near_sdk::serde_json::from_str(&json).unwrap();
     assert!(decoded.user_context_data.is_empty());
+    assert_eq!(decoded.context_storage, ContextStorage::HashOnly);
 }
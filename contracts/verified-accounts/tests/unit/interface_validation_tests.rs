@@ -53,3 +53,32 @@ fn test_verification_summary_json_invalid_timestamp_type_fails() {
         "Deserialization should fail when verified_at is not a number"
     );
 }
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Interface Tests")]
+#[allure_sub_suite("Validation")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "validation", "versioning")]
+#[allure_test]
+#[test]
+fn test_interface_version_compatibility() {
+    // Same major and at least the required minor/patch is compatible
+    assert!(is_interface_compatible("1.1.0", "1.0.0"));
+    assert!(is_interface_compatible("1.1.0", "1.1.0"));
+    assert!(is_interface_compatible("1.2.3", "1.2.1"));
+    assert!(is_interface_compatible(
+        INTERFACE_VERSION,
+        INTERFACE_VERSION
+    ));
+
+    // Older deployments and major bumps are rejected
+    assert!(!is_interface_compatible("1.0.0", "1.1.0"));
+    assert!(!is_interface_compatible("2.0.0", "1.1.0"));
+    assert!(!is_interface_compatible("0.9.0", "1.0.0"));
+
+    // Malformed versions are never compatible
+    assert!(!is_interface_compatible("1.1", "1.0.0"));
+    assert!(!is_interface_compatible("1.1.0.0", "1.0.0"));
+    assert!(!is_interface_compatible("v1.1.0", "1.0.0"));
+    assert!(!is_interface_compatible("1.1.0", ""));
+}
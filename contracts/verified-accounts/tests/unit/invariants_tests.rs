@@ -60,7 +60,7 @@ fn test_invariant_verified_count_starts_at_zero() {
     });
 
     step("Verify list_verifications returns empty list", || {
-        assert!(contract.list_verifications(0, 100).is_empty());
+        assert!(contract.list_verifications(0, 100).items.is_empty());
     });
 }
 
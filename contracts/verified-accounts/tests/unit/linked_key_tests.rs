@@ -0,0 +1,193 @@
+//! Linked public key tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, get_context, parse_event,
+    LinkedKeyRotatedEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::{testing_env, AccountId};
+use verified_accounts::{linked_key_challenge, NearSignatureData, VersionedContract};
+
+/// Rotation signature by a fresh key for `account_id`
+fn rotation_signature(account_id: &AccountId, nonce: u8) -> NearSignatureData {
+    create_valid_signature(
+        &create_signer(account_id),
+        account_id,
+        &linked_key_challenge(account_id),
+        &[nonce; 32],
+        &accounts(0),
+    )
+}
+
+/// Contract with `accounts(2)` and `accounts(3)` verified; returns their verification signatures
+fn setup() -> (VersionedContract, NearSignatureData, NearSignatureData) {
+    testing_env!(get_context(accounts(1)).build());
+    let mut contract = VersionedContract::new(accounts(1));
+    let mut signatures = Vec::new();
+    for (user, nonce) in [(accounts(2), 1), (accounts(3), 2)] {
+        let sig_data = create_valid_signature(
+            &create_signer(&user),
+            &user,
+            "Identify myself",
+            &[nonce; 32],
+            &accounts(0),
+        );
+        contract.store_verification(user, sig_data.clone(), "ctx".to_string(), None);
+        signatures.push(sig_data);
+    }
+    let bob = signatures.pop().unwrap();
+    let alice = signatures.pop().unwrap();
+    (contract, alice, bob)
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Linked Keys")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "linked-key")]
+#[allure_description(
+    "Verifies that store_verification links the signing public key and that the owner can rotate it with a signature from the new key."
+)]
+#[allure_test]
+#[test]
+fn test_linked_key_stored_and_rotated() {
+    let user = accounts(2);
+    let (mut contract, alice_sig, _) = step("Verify two accounts", setup);
+
+    step("Signing key is linked", || {
+        assert_eq!(
+            contract.get_linked_key(user.clone()),
+            Some(alice_sig.public_key.clone())
+        );
+        assert_eq!(contract.get_linked_key(accounts(4)), None);
+    });
+
+    step("Owner rotates to a new key", || {
+        testing_env!(get_context(user.clone()).build());
+        let sig_data = rotation_signature(&user, 10);
+        let new_key = sig_data.public_key.clone();
+        contract.rotate_linked_key(sig_data);
+
+        assert_eq!(contract.get_linked_key(user.clone()), Some(new_key.clone()));
+        let event: LinkedKeyRotatedEvent = parse_event(&get_logs(), "linked_key_rotated")
+            .expect("linked_key_rotated event not found");
+        assert_eq!(event.account_id, user);
+        assert_eq!(event.public_key, new_key);
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Linked Keys")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "linked-key", "security")]
+#[allure_description(
+    "Verifies that rotate_linked_key rejects unverified callers, signatures for other accounts, wrong challenges and invalid signatures."
+)]
+#[allure_test]
+#[test]
+fn test_rotate_linked_key_rules() {
+    let user = accounts(2);
+    let (mut contract, _, _) = step("Verify two accounts", setup);
+
+    step("Unverified caller is rejected", || {
+        let other = accounts(4);
+        testing_env!(get_context(other.clone()).build());
+        let sig_data = rotation_signature(&other, 10);
+        assert_panic_with(|| contract.rotate_linked_key(sig_data), "ERR_NOT_VERIFIED");
+    });
+
+    step("Signature for another account is rejected", || {
+        testing_env!(get_context(user.clone()).build());
+        let sig_data = rotation_signature(&accounts(3), 11);
+        assert_panic_with(
+            || contract.rotate_linked_key(sig_data),
+            "ERR_SIGNATURE_ACCOUNT_MISMATCH",
+        );
+    });
+
+    step(
+        "Challenge other than the rotation challenge is rejected",
+        || {
+            let sig_data = create_valid_signature(
+                &create_signer(&user),
+                &user,
+                "Identify myself",
+                &[12; 32],
+                &accounts(0),
+            );
+            assert_panic_with(
+                || contract.rotate_linked_key(sig_data),
+                "ERR_INVALID_CHALLENGE",
+            );
+        },
+    );
+
+    step("Signature not made by the new key is rejected", || {
+        let mut sig_data = rotation_signature(&user, 13);
+        sig_data.public_key = rotation_signature(&user, 13).public_key;
+        assert_panic_with(
+            || contract.rotate_linked_key(sig_data),
+            "ERR_INVALID_SIGNATURE",
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Linked Keys")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "linked-key", "security", "replay")]
+#[allure_description(
+    "Verifies that another account's verification or rotation signature cannot be replayed to link its key, and that rotation nonces cannot be reused."
+)]
+#[allure_test]
+#[test]
+fn test_rotate_linked_key_replay_rejected() {
+    let alice = accounts(2);
+    let mallory = accounts(3);
+    let (mut contract, alice_verification, _) = step("Verify two accounts", setup);
+
+    step("Alice's public verification signature is rejected", || {
+        testing_env!(get_context(mallory.clone()).build());
+        let mut replayed = alice_verification.clone();
+        replayed.account_id = mallory.clone();
+        assert_panic_with(
+            || contract.rotate_linked_key(replayed),
+            "ERR_INVALID_CHALLENGE",
+        );
+    });
+
+    let alice_rotation = step("Alice rotates her key", || {
+        testing_env!(get_context(alice.clone()).build());
+        let sig_data = rotation_signature(&alice, 20);
+        contract.rotate_linked_key(sig_data.clone());
+        sig_data
+    });
+
+    step("Alice's rotation signature is rejected for Mallory", || {
+        testing_env!(get_context(mallory.clone()).build());
+        let mut replayed = alice_rotation.clone();
+        replayed.account_id = mallory.clone();
+        assert_panic_with(
+            || contract.rotate_linked_key(replayed),
+            "ERR_INVALID_CHALLENGE",
+        );
+        assert_ne!(
+            contract.get_linked_key(mallory.clone()),
+            Some(alice_rotation.public_key.clone())
+        );
+    });
+
+    step("A used nonce is rejected", || {
+        testing_env!(get_context(alice.clone()).build());
+        assert_panic_with(
+            || contract.rotate_linked_key(alice_rotation.clone()),
+            "ERR_NONCE_REUSED",
+        );
+        testing_env!(get_context(mallory.clone()).build());
+        let reused = rotation_signature(&mallory, 20);
+        assert_panic_with(|| contract.rotate_linked_key(reused), "ERR_NONCE_REUSED");
+    });
+}
@@ -0,0 +1,84 @@
+//! Backend heartbeat and liveness tests for verified-accounts contract
+
+use super::helpers::{assert_panic_with, get_context};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::accounts;
+use near_sdk::testing_env;
+use verified_accounts::{BackendHeartbeat, VersionedContract};
+
+const DAY_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Backend Liveness")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "liveness")]
+#[allure_description(
+    "Verifies that heartbeats are recorded and that liveness turns stale when none arrives for 7 days."
+)]
+#[allure_test]
+#[test]
+fn test_backend_liveness() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Stale before any heartbeat", || {
+        let liveness = contract.get_backend_liveness();
+        assert_eq!(liveness.last_heartbeat, None);
+        assert_eq!(liveness.stale_for_ns, None);
+        assert!(liveness.stale);
+    });
+
+    step("Backend sends a heartbeat", || {
+        testing_env!(get_context(accounts(1))
+            .block_height(100)
+            .block_timestamp(DAY_NS)
+            .build());
+        contract.heartbeat();
+        let liveness = contract.get_backend_liveness();
+        assert_eq!(
+            liveness.last_heartbeat,
+            Some(BackendHeartbeat {
+                block_height: 100,
+                timestamp: DAY_NS,
+            })
+        );
+        assert_eq!(liveness.stale_for_ns, Some(0));
+        assert!(!liveness.stale);
+    });
+
+    step("Fresh for 7 days, stale afterwards", || {
+        testing_env!(get_context(accounts(1)).block_timestamp(8 * DAY_NS).build());
+        assert!(!contract.get_backend_liveness().stale);
+
+        testing_env!(get_context(accounts(1))
+            .block_timestamp(8 * DAY_NS + 1)
+            .build());
+        let liveness = contract.get_backend_liveness();
+        assert_eq!(liveness.stale_for_ns, Some(7 * DAY_NS + 1));
+        assert!(liveness.stale);
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Backend Liveness")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "liveness", "security")]
+#[allure_description("Verifies that only the backend wallet can send heartbeats.")]
+#[allure_test]
+#[test]
+fn test_heartbeat_backend_only() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Non-backend heartbeat is rejected", || {
+        testing_env!(get_context(accounts(2)).build());
+        assert_panic_with(|| contract.heartbeat(), "ERR_UNAUTHORIZED");
+        assert_eq!(contract.get_backend_liveness().last_heartbeat, None);
+    });
+}
@@ -0,0 +1,129 @@
+//! Membership diff log tests for verified-accounts contract
+
+use super::helpers::{create_signer, create_valid_signature, get_context};
+use allure_rs::prelude::*;
+use near_sdk::store::IterableMap;
+use near_sdk::test_utils::accounts;
+use near_sdk::testing_env;
+use near_sdk::AccountId;
+use verified_accounts::interface::VerificationV1;
+use verified_accounts::{
+    ContractV1, MembershipChange, StorageKey, VersionedContract, VersionedVerification,
+};
+
+fn store(contract: &mut VersionedContract, user: &AccountId, nonce: u8) {
+    let signer = create_signer(user);
+    let sig_data =
+        create_valid_signature(&signer, user, "Identify myself", &[nonce; 32], &accounts(0));
+    contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Membership Sync")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "query", "membership")]
+#[allure_description(
+    "Verifies that each stored verification appends a gapless Added diff that can be paged by sequence number."
+)]
+#[allure_test]
+#[test]
+fn test_membership_diffs_follow_store_order() {
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    step("Empty log starts at seq 0", || {
+        assert_eq!(contract.get_membership_seq(), 0);
+        assert!(contract.get_membership_diffs(0, 10).is_empty());
+    });
+
+    step("Store three verifications", || {
+        for (i, user) in [accounts(2), accounts(3), accounts(4)].iter().enumerate() {
+            store(&mut contract, user, i as u8 + 1);
+        }
+    });
+
+    step("Page through diffs", || {
+        assert_eq!(contract.get_membership_seq(), 3);
+
+        let first = contract.get_membership_diffs(0, 2);
+        assert_eq!(first.len(), 2);
+        assert_eq!(first[0].seq, 0);
+        assert_eq!(first[0].account_id, accounts(2));
+        assert_eq!(first[0].change, MembershipChange::Added);
+        assert_eq!(first[1].seq, 1);
+        assert_eq!(first[1].account_id, accounts(3));
+
+        let rest = contract.get_membership_diffs(2, 10);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].seq, 2);
+        assert_eq!(rest[0].account_id, accounts(4));
+
+        assert!(contract.get_membership_diffs(3, 10).is_empty());
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Membership Sync")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "migration", "membership")]
+#[allure_description(
+    "Verifies that accounts verified before the V2 upgrade are replayed as Added diffs and new diffs continue the sequence."
+)]
+#[allure_test]
+#[test]
+fn test_membership_diffs_cover_pre_upgrade_accounts() {
+    let mut contract = step("Build V1 state with two verified accounts", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        let mut verifications = IterableMap::new(StorageKey::Accounts);
+        for (i, user) in [accounts(2), accounts(3)].iter().enumerate() {
+            verifications.insert(
+                user.clone(),
+                VersionedVerification::V1(VerificationV1 {
+                    near_account_id: user.clone(),
+                    verified_at: i as u64 + 100,
+                    user_context_data: String::new(),
+                }),
+            );
+        }
+        VersionedContract::V1(ContractV1 {
+            backend_wallet: backend,
+            verifications,
+            paused: false,
+        })
+    });
+
+    step("V1 state reports synthesized diffs", || {
+        assert_eq!(contract.get_membership_seq(), 2);
+        let diffs = contract.get_membership_diffs(1, 10);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].seq, 1);
+        assert_eq!(diffs[0].account_id, accounts(3));
+        assert_eq!(diffs[0].timestamp, 101);
+    });
+
+    step("V1 state reports verification timestamps", || {
+        let stats = contract.get_statistics();
+        assert_eq!(stats.first_verified_at, Some(100));
+        assert_eq!(stats.last_verified_at, Some(101));
+        assert!(contract.is_account_verified_since(accounts(2), 0));
+        assert!(!contract.is_account_verified_since(accounts(2), u64::MAX));
+    });
+
+    step("Store after upgrade continues the sequence", || {
+        store(&mut contract, &accounts(4), 1);
+        assert_eq!(contract.get_state_version(), 2);
+        assert_eq!(contract.get_membership_seq(), 3);
+
+        let diffs = contract.get_membership_diffs(0, 10);
+        let seqs: Vec<u64> = diffs.iter().map(|d| d.seq).collect();
+        let ids: Vec<AccountId> = diffs.iter().map(|d| d.account_id.clone()).collect();
+        assert_eq!(seqs, vec![0, 1, 2]);
+        assert_eq!(ids, vec![accounts(2), accounts(3), accounts(4)]);
+    });
+}
@@ -0,0 +1,210 @@
+//! MPC (chain signature) authorization tests for verified-accounts contract
+
+use super::helpers::{assert_panic_with, create_signer, create_valid_signature, get_context};
+use allure_rs::prelude::*;
+use near_crypto::{KeyType, SecretKey};
+use near_sdk::json_types::Base64VecU8;
+use near_sdk::test_utils::accounts;
+use near_sdk::{env, testing_env, AccountId};
+use verified_accounts::{MpcStorePayload, NearSignatureData, VersionedContract};
+
+/// Sign `sha256(borsh(MpcStorePayload))` with a secp256k1 key, returning `r || s || v`
+fn mpc_sign(
+    key: &SecretKey,
+    user: &AccountId,
+    sig_data: &NearSignatureData,
+    user_context_data: &str,
+    nonce: u64,
+) -> Base64VecU8 {
+    let payload = MpcStorePayload {
+        contract_id: accounts(0),
+        nonce,
+        near_account_id: user.clone(),
+        user_signature: sig_data.signature.0.clone(),
+        user_context_hash: env::sha256_array(user_context_data.as_bytes()),
+        referred_by: sig_data.referred_by.clone(),
+    };
+    let digest = env::sha256(near_sdk::borsh::to_vec(&payload).unwrap());
+    match key.sign(&digest) {
+        near_crypto::Signature::SECP256K1(sig) => Base64VecU8::from(<[u8; 65]>::from(sig).to_vec()),
+        _ => panic!("expected a secp256k1 signature"),
+    }
+}
+
+/// Contract with `key` configured as the MPC key
+fn contract_with_mpc_key(key: &SecretKey) -> VersionedContract {
+    testing_env!(get_context(accounts(1)).build());
+    let mut contract = VersionedContract::new(accounts(1));
+    let public_key = key.public_key().key_data().to_vec();
+    contract.set_mpc_public_key(Some(Base64VecU8::from(public_key)));
+    contract
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("MPC Authorization")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "security", "authorization", "mpc")]
+#[allure_description(
+    "Verifies that any relayer can store a verification carrying a valid MPC signature, and that the nonce cannot be replayed."
+)]
+#[allure_test]
+#[test]
+fn test_store_verification_mpc() {
+    let key = SecretKey::from_random(KeyType::SECP256K1);
+    let mut contract = step("Configure the MPC key", || contract_with_mpc_key(&key));
+
+    step("A relayer stores an MPC-authorized verification", || {
+        testing_env!(get_context(accounts(4)).build());
+        let user = accounts(2);
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        let mpc_signature = mpc_sign(&key, &user, &sig_data, "ctx", 1);
+        contract.store_verification_mpc(
+            user.clone(),
+            sig_data,
+            "ctx".to_string(),
+            1,
+            mpc_signature,
+        );
+        assert!(contract.is_verified(user));
+    });
+
+    step("Reusing a nonce is rejected", || {
+        let user = accounts(3);
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[2; 32], &accounts(0));
+        let mpc_signature = mpc_sign(&key, &user, &sig_data, "ctx", 1);
+        assert_panic_with(
+            || {
+                contract.store_verification_mpc(
+                    user.clone(),
+                    sig_data,
+                    "ctx".to_string(),
+                    1,
+                    mpc_signature,
+                )
+            },
+            "ERR_STALE_MPC_NONCE: MPC nonce must be greater than 1",
+        );
+    });
+
+    step("A signed referrer is credited", || {
+        let user = accounts(3);
+        let signer = create_signer(&user);
+        let mut sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[2; 32], &accounts(0));
+        sig_data.referred_by = Some(accounts(2));
+        let mpc_signature = mpc_sign(&key, &user, &sig_data, "ctx", 2);
+        contract.store_verification_mpc(
+            user.clone(),
+            sig_data,
+            "ctx".to_string(),
+            2,
+            mpc_signature,
+        );
+        assert_eq!(contract.get_referrer(user), Some(accounts(2)));
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("MPC Authorization")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "security", "authorization", "mpc")]
+#[allure_description(
+    "Verifies that MPC writes are rejected without a configured key, with a foreign key, or when the context data or referrer was altered."
+)]
+#[allure_test]
+#[test]
+fn test_store_verification_mpc_rejections() {
+    let key = SecretKey::from_random(KeyType::SECP256K1);
+    let user = accounts(2);
+    let signer = create_signer(&user);
+
+    step("No key configured", || {
+        testing_env!(get_context(accounts(1)).build());
+        let mut contract = VersionedContract::new(accounts(1));
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        let mpc_signature = mpc_sign(&key, &user, &sig_data, "ctx", 1);
+        assert_panic_with(
+            || {
+                contract.store_verification_mpc(
+                    user.clone(),
+                    sig_data,
+                    "ctx".to_string(),
+                    1,
+                    mpc_signature,
+                )
+            },
+            "ERR_MPC_NOT_CONFIGURED",
+        );
+        assert_panic_with(
+            || contract.set_mpc_public_key(Some(Base64VecU8::from(vec![4; 65]))),
+            "ERR_INVALID_MPC_KEY: MPC public key must be 64 bytes, got 65",
+        );
+    });
+
+    let mut contract = contract_with_mpc_key(&key);
+
+    step("Signature from another key", || {
+        let other = SecretKey::from_random(KeyType::SECP256K1);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        let mpc_signature = mpc_sign(&other, &user, &sig_data, "ctx", 1);
+        assert_panic_with(
+            || {
+                contract.store_verification_mpc(
+                    user.clone(),
+                    sig_data,
+                    "ctx".to_string(),
+                    1,
+                    mpc_signature,
+                )
+            },
+            "ERR_INVALID_MPC_SIGNATURE",
+        );
+    });
+
+    step("Context data differs from the signed payload", || {
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        let mpc_signature = mpc_sign(&key, &user, &sig_data, "ctx", 1);
+        assert_panic_with(
+            || {
+                contract.store_verification_mpc(
+                    user.clone(),
+                    sig_data,
+                    "tampered".to_string(),
+                    1,
+                    mpc_signature,
+                )
+            },
+            "ERR_INVALID_MPC_SIGNATURE",
+        );
+        assert!(!contract.is_verified(user.clone()));
+    });
+
+    step("Referrer differs from the signed payload", || {
+        let mut sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        let mpc_signature = mpc_sign(&key, &user, &sig_data, "ctx", 1);
+        sig_data.referred_by = Some(accounts(3));
+        assert_panic_with(
+            || {
+                contract.store_verification_mpc(
+                    user.clone(),
+                    sig_data,
+                    "ctx".to_string(),
+                    1,
+                    mpc_signature,
+                )
+            },
+            "ERR_INVALID_MPC_SIGNATURE",
+        );
+        assert_eq!(contract.get_referrer(user.clone()), None);
+    });
+}
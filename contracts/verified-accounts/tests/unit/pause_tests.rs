@@ -155,12 +155,16 @@ fn test_store_verification_when_paused() {
                     challenge: "Identify myself".to_string(),
                     nonce: vec![0; 32].into(),
                     recipient: accounts(0),
+                    app_id: None,
+                    terms_hash: None,
+                    referred_by: None,
                 };
 
                 contract.store_verification(
                     user,
                     sig_data,
                     "test_user_context_data".to_string(),
+                    None,
                 );
             },
             "Contract is paused - no new verifications allowed",
@@ -0,0 +1,121 @@
+//! Scoped backend permission tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, get_context, parse_event,
+    PermissionGrantedEvent, PermissionRevokedEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::testing_env;
+use verified_accounts::{BackendMethod, PermissionGrant, VersionedContract};
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Permissions")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "security", "authorization")]
+#[allure_description(
+    "Verifies that a grantee can call only the granted methods, and only until the grant expires or is revoked."
+)]
+#[allure_test]
+#[test]
+fn test_scoped_permission_lifecycle() {
+    let worker = accounts(3);
+    let mut contract = step("Grant the worker store_verification only", || {
+        testing_env!(get_context(accounts(1)).block_timestamp(100).build());
+        let mut contract = VersionedContract::new(accounts(1));
+        contract.grant_permission(
+            worker.clone(),
+            vec![
+                BackendMethod::StoreVerification,
+                BackendMethod::StoreVerification,
+            ],
+            1_000,
+        );
+
+        let event: PermissionGrantedEvent = parse_event(&get_logs(), "permission_granted")
+            .expect("permission_granted event not found");
+        assert_eq!(event.account_id, worker);
+        assert_eq!(event.methods, vec![BackendMethod::StoreVerification]);
+        assert_eq!(
+            contract.get_permission(worker.clone()),
+            Some(PermissionGrant {
+                methods: vec![BackendMethod::StoreVerification],
+                expires_at: 1_000,
+            })
+        );
+        contract
+    });
+
+    step("Worker can store but not pause", || {
+        testing_env!(get_context(worker.clone()).block_timestamp(200).build());
+        let user = accounts(2);
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
+        assert!(contract.is_verified(user));
+
+        assert_panic_with(
+            || contract.pause(),
+            "ERR_UNAUTHORIZED: Only backend wallet can pause contract",
+        );
+        assert_panic_with(
+            || contract.grant_permission(accounts(4), vec![BackendMethod::Pause], 1_000),
+            "ERR_UNAUTHORIZED: Only backend wallet can grant permissions",
+        );
+    });
+
+    step("Grant is ignored after expiry", || {
+        testing_env!(get_context(worker.clone()).block_timestamp(1_000).build());
+        let user = accounts(4);
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[2; 32], &accounts(0));
+        assert_panic_with(
+            || contract.store_verification(user, sig_data, "ctx".to_string(), None),
+            "ERR_UNAUTHORIZED: Only backend wallet can store verifications",
+        );
+    });
+
+    step("Backend revokes the grant", || {
+        testing_env!(get_context(accounts(1)).block_timestamp(1_000).build());
+        contract.revoke_permission(worker.clone());
+        assert_eq!(contract.get_permission(worker.clone()), None);
+
+        let event: PermissionRevokedEvent = parse_event(&get_logs(), "permission_revoked")
+            .expect("permission_revoked event not found");
+        assert_eq!(event.account_id, worker);
+
+        assert_panic_with(
+            || contract.revoke_permission(worker.clone()),
+            "ERR_PERMISSION_NOT_FOUND",
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Permissions")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "validation", "authorization")]
+#[allure_description("Verifies that empty or already-expired grants are rejected.")]
+#[allure_test]
+#[test]
+fn test_invalid_permission_grants() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).block_timestamp(100).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Reject grants without methods or with past expiry", || {
+        assert_panic_with(
+            || contract.grant_permission(accounts(3), vec![], 1_000),
+            "ERR_INVALID_PERMISSION: Grant must include at least one method",
+        );
+        assert_panic_with(
+            || contract.grant_permission(accounts(3), vec![BackendMethod::Pause], 100),
+            "ERR_INVALID_PERMISSION: Grant expiry must be in the future",
+        );
+    });
+}
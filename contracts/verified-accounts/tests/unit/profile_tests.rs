@@ -0,0 +1,113 @@
+//! Member profile tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, get_context, parse_event,
+    ProfileUpdatedEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::testing_env;
+use verified_accounts::{MemberProfile, VersionedContract};
+
+fn profile() -> MemberProfile {
+    MemberProfile {
+        handle_hash: Some("ab".repeat(32)),
+        avatar_cid: Some("bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string()),
+        links: vec!["https://example.org/alice".to_string()],
+    }
+}
+
+fn verified_contract() -> VersionedContract {
+    let backend = accounts(1);
+    testing_env!(get_context(backend.clone()).build());
+    let mut contract = VersionedContract::new(backend);
+    let user = accounts(2);
+    let signer = create_signer(&user);
+    let sig_data =
+        create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+    contract.store_verification(user, sig_data, "ctx".to_string(), None);
+    contract
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Member Profiles")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "profile")]
+#[allure_description(
+    "Verifies that a verified member can set, read back and clear an opt-in profile, with an event for each change."
+)]
+#[allure_test]
+#[test]
+fn test_set_and_clear_profile() {
+    let mut contract = step("Verify an account", verified_contract);
+
+    step("Verified member without profile", || {
+        let info = contract.get_member_profile(accounts(2)).unwrap();
+        assert_eq!(info.verification.near_account_id, accounts(2));
+        assert!(info.profile.is_none());
+        assert!(contract.get_member_profile(accounts(3)).is_none());
+    });
+
+    step("Set profile", || {
+        testing_env!(get_context(accounts(2)).build());
+        contract.set_profile(profile());
+        let event: ProfileUpdatedEvent =
+            parse_event(&get_logs(), "profile_updated").expect("profile_updated event not found");
+        assert_eq!(event.account_id, accounts(2));
+        assert!(!event.cleared);
+        let info = contract.get_member_profile(accounts(2)).unwrap();
+        assert_eq!(info.profile, Some(profile()));
+    });
+
+    step("Clear profile", || {
+        testing_env!(get_context(accounts(2)).build());
+        contract.clear_profile();
+        let event: ProfileUpdatedEvent =
+            parse_event(&get_logs(), "profile_updated").expect("profile_updated event not found");
+        assert!(event.cleared);
+        assert!(contract
+            .get_member_profile(accounts(2))
+            .unwrap()
+            .profile
+            .is_none());
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Member Profiles")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "profile", "validation")]
+#[allure_description(
+    "Verifies that only verified accounts can set a profile and that malformed or oversized fields are rejected."
+)]
+#[allure_test]
+#[test]
+fn test_set_profile_validation() {
+    let mut contract = step("Verify an account", verified_contract);
+
+    step("Unverified account is rejected", || {
+        testing_env!(get_context(accounts(3)).build());
+        assert_panic_with(|| contract.set_profile(profile()), "ERR_NOT_VERIFIED");
+    });
+
+    step("Malformed handle hash is rejected", || {
+        testing_env!(get_context(accounts(2)).build());
+        let mut bad = profile();
+        bad.handle_hash = Some("alice".to_string());
+        assert_panic_with(|| contract.set_profile(bad), "ERR_INVALID_PROFILE");
+    });
+
+    step("Too many links are rejected", || {
+        let mut bad = profile();
+        bad.links = vec!["https://example.org".to_string(); 6];
+        assert_panic_with(|| contract.set_profile(bad), "ERR_INVALID_PROFILE");
+    });
+
+    step("Oversized link is rejected", || {
+        let mut bad = profile();
+        bad.links = vec!["a".repeat(257)];
+        assert_panic_with(|| contract.set_profile(bad), "ERR_INPUT_TOO_LONG");
+    });
+}
@@ -48,8 +48,10 @@ fn test_read_functions() {
     );
 
     step("Verify list_verifications returns empty list", || {
-        let verifications_list = contract.list_verifications(0, 10);
-        assert_eq!(verifications_list.len(), 0);
+        let page = contract.list_verifications(0, 10);
+        assert!(page.items.is_empty());
+        assert_eq!(page.total, 0);
+        assert_eq!(page.next_index, None);
     });
 }
 
@@ -79,7 +81,7 @@ fn test_read_functions_with_verified_accounts() {
             &[9; 32],
             &accounts(0),
         );
-        contract.store_verification(user_a, sig_a, "ctx".to_string());
+        contract.store_verification(user_a, sig_a, "ctx".to_string(), None);
     });
 
     step("Store second verified account", || {
@@ -92,7 +94,7 @@ fn test_read_functions_with_verified_accounts() {
             &[10; 32],
             &accounts(0),
         );
-        contract.store_verification(user_b, sig_b, "ctx".to_string());
+        contract.store_verification(user_b, sig_b, "ctx".to_string(), None);
     });
 
     step("Verify count is 2", || {
@@ -101,13 +103,18 @@ fn test_read_functions_with_verified_accounts() {
 
     step("Test pagination returns correct slices", || {
         let first_page = contract.list_verifications(0, 1);
-        assert_eq!(first_page.len(), 1);
+        assert_eq!(first_page.items.len(), 1);
+        assert_eq!(first_page.total, 2);
+        assert_eq!(first_page.next_index, Some(1));
         let second_page = contract.list_verifications(1, 10);
-        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page.items.len(), 1);
+        assert_eq!(second_page.next_index, None);
         let capped = contract.list_verifications(0, 200);
-        assert_eq!(capped.len(), 2);
+        assert_eq!(capped.items.len(), 2);
         let empty_page = contract.list_verifications(5, 10);
-        assert!(empty_page.is_empty());
+        assert!(empty_page.items.is_empty());
+        assert_eq!(empty_page.total, 2);
+        assert_eq!(empty_page.next_index, None);
     });
 
     step("Test batch verification returns correct flags", || {
@@ -145,7 +152,122 @@ fn test_pagination_with_large_limit_on_empty() {
         "Call list_verifications with limit > 100 on empty data",
         || {
             let verifications_list = contract.list_verifications(0, 200);
-            assert_eq!(verifications_list.len(), 0);
+            assert!(verifications_list.items.is_empty());
         },
     );
 }
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Read Functions")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "query", "snapshot", "statistics")]
+#[allure_description(
+    "Verifies snapshot queries filter by verification time and statistics track first/last verification."
+)]
+#[allure_test]
+#[test]
+fn test_snapshot_and_statistics_queries() {
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    step("Verify statistics on empty contract", || {
+        let stats = contract.get_statistics();
+        assert_eq!(stats.verified_count, 0);
+        assert!(stats.first_verified_at.is_none());
+        assert!(stats.last_verified_at.is_none());
+        assert!(!stats.paused);
+        assert_eq!(stats.state_version, 2);
+        assert_eq!(stats.record_version, 2);
+    });
+
+    step("Store verifications at increasing timestamps", || {
+        for (i, ts) in [(2usize, 100u64), (3, 200), (4, 300)] {
+            let mut context = get_context(accounts(1));
+            context.block_timestamp(ts);
+            testing_env!(context.build());
+            let user = accounts(i);
+            let signer = create_signer(&user);
+            let sig = create_valid_signature(
+                &signer,
+                &user,
+                "Identify myself",
+                &[i as u8; 32],
+                &accounts(0),
+            );
+            contract.store_verification(user, sig, "ctx".to_string(), None);
+        }
+    });
+
+    step(
+        "Verify list_verifications_before filters by timestamp",
+        || {
+            let before: Vec<_> = contract
+                .list_verifications_before(300, 0, 10)
+                .items
+                .into_iter()
+                .map(|s| s.near_account_id)
+                .collect();
+            assert_eq!(before, vec![accounts(2), accounts(3)]);
+
+            assert!(contract
+                .list_verifications_before(100, 0, 10)
+                .items
+                .is_empty());
+            let short_page = contract.list_verifications_before(150, 0, 2);
+            assert_eq!(short_page.items.len(), 1);
+            assert_eq!(short_page.next_index, Some(2));
+            assert_eq!(
+                contract.list_verifications_before(1_000, 1, 1).items.len(),
+                1
+            );
+        },
+    );
+
+    step("Verify statistics reflect stored verifications", || {
+        let stats = contract.get_statistics();
+        assert_eq!(stats.verified_count, 3);
+        assert_eq!(stats.first_verified_at, Some(100));
+        assert_eq!(stats.last_verified_at, Some(300));
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Read Functions")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "query", "composability")]
+#[allure_description(
+    "Verifies is_account_verified_since only accepts accounts verified for at least the requested duration."
+)]
+#[allure_test]
+#[test]
+fn test_is_account_verified_since() {
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    step("Store a verification at t=1000", || {
+        testing_env!(get_context(accounts(1)).block_timestamp(1_000).build());
+        let user = accounts(2);
+        let signer = create_signer(&user);
+        let sig = create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        contract.store_verification(user, sig, "ctx".to_string(), None);
+    });
+
+    step("Check duration thresholds at t=1500", || {
+        testing_env!(get_context(accounts(1)).block_timestamp(1_500).build());
+        assert!(contract.is_account_verified_since(accounts(2), 0));
+        assert!(contract.is_account_verified_since(accounts(2), 500));
+        assert!(!contract.is_account_verified_since(accounts(2), 501));
+    });
+
+    step("Unverified accounts never qualify", || {
+        assert!(!contract.is_account_verified_since(accounts(3), 0));
+    });
+}
@@ -0,0 +1,137 @@
+//! NEP-413 recipient policy tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, get_context, parse_event,
+    ConfigUpdatedEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::testing_env;
+use near_sdk::AccountId;
+use verified_accounts::{NearSignatureData, RecipientPolicy, VersionedContract};
+
+fn signature_to(user: &AccountId, recipient: &AccountId, nonce: u8) -> NearSignatureData {
+    let signer = create_signer(user);
+    create_valid_signature(&signer, user, "Identify myself", &[nonce; 32], recipient)
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Recipient Policy")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "security", "signature")]
+#[allure_description(
+    "Verifies that each recipient policy accepts exactly the configured NEP-413 recipients."
+)]
+#[allure_test]
+#[test]
+fn test_recipient_policy_modes() {
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    step("Default policy requires the contract as recipient", || {
+        assert_eq!(
+            contract.get_config().recipient_policy,
+            RecipientPolicy::Contract
+        );
+        let user = accounts(2);
+        assert_panic_with(
+            || {
+                contract.store_verification(
+                    user.clone(),
+                    signature_to(&user, &user, 1),
+                    "ctx".to_string(),
+                    None,
+                )
+            },
+            "ERR_SIGNATURE_RECIPIENT_MISMATCH: Signature recipient must match contract account",
+        );
+    });
+
+    step(
+        "Signer policy requires the signing account as recipient",
+        || {
+            contract.set_recipient_policy(RecipientPolicy::Signer);
+            let event: ConfigUpdatedEvent =
+                parse_event(&get_logs(), "config_updated").expect("config_updated event not found");
+            assert_eq!(event.field, "recipient_policy");
+
+            let user = accounts(2);
+            assert_panic_with(
+                || {
+                    contract.store_verification(
+                        user.clone(),
+                        signature_to(&user, &accounts(0), 2),
+                        "ctx".to_string(),
+                        None,
+                    )
+                },
+                "Signature recipient must match signing account",
+            );
+            contract.store_verification(
+                user.clone(),
+                signature_to(&user, &user, 3),
+                "ctx".to_string(),
+                None,
+            );
+            assert!(contract.is_verified(user));
+        },
+    );
+
+    step("Either policy accepts both recipients", || {
+        contract.set_recipient_policy(RecipientPolicy::Either);
+        let (user_a, user_b) = (accounts(3), accounts(4));
+        contract.store_verification(
+            user_a.clone(),
+            signature_to(&user_a, &user_a, 4),
+            "ctx".to_string(),
+            None,
+        );
+        contract.store_verification(
+            user_b.clone(),
+            signature_to(&user_b, &accounts(0), 5),
+            "ctx".to_string(),
+            None,
+        );
+        assert!(contract.is_verified(user_a));
+        assert!(contract.is_verified(user_b));
+
+        let user = accounts(5);
+        assert_panic_with(
+            || {
+                contract.store_verification(
+                    user.clone(),
+                    signature_to(&user, &accounts(2), 6),
+                    "ctx".to_string(),
+                    None,
+                )
+            },
+            "Signature recipient must match contract account or signing account",
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Recipient Policy")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "security", "authorization")]
+#[allure_description("Verifies that only the backend wallet can change the recipient policy.")]
+#[allure_test]
+#[test]
+fn test_set_recipient_policy_unauthorized() {
+    let mut contract = step("Initialize contract with unauthorized caller", || {
+        testing_env!(get_context(accounts(0)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Attempt to change the policy", || {
+        assert_panic_with(
+            || contract.set_recipient_policy(RecipientPolicy::Either),
+            "ERR_UNAUTHORIZED: Only backend wallet can set recipient policy",
+        );
+    });
+}
@@ -0,0 +1,94 @@
+//! Referral tracking tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, get_context, parse_event,
+    ReferralRecordedEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::testing_env;
+use near_sdk::AccountId;
+use verified_accounts::VersionedContract;
+
+fn store(contract: &mut VersionedContract, user: &AccountId, referred_by: Option<AccountId>) {
+    let signer = create_signer(user);
+    let mut sig_data =
+        create_valid_signature(&signer, user, "Identify myself", &[1; 32], &accounts(0));
+    sig_data.referred_by = referred_by;
+    contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Referrals")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "referral")]
+#[allure_description(
+    "Verifies that a verification stored with a verified referrer records the referrer, increments its count and emits referral_recorded."
+)]
+#[allure_test]
+#[test]
+fn test_referral_recorded() {
+    let mut contract = step("Verify the referrer", || {
+        testing_env!(get_context(accounts(1)).build());
+        let mut contract = VersionedContract::new(accounts(1));
+        store(&mut contract, &accounts(2), None);
+        contract
+    });
+
+    step("Verify a referred account", || {
+        store(&mut contract, &accounts(3), Some(accounts(2)));
+        let event: ReferralRecordedEvent = parse_event(&get_logs(), "referral_recorded")
+            .expect("referral_recorded event not found");
+        assert_eq!(event.account_id, accounts(3));
+        assert_eq!(event.referred_by, accounts(2));
+    });
+
+    step("Referral views reflect the referral", || {
+        assert_eq!(contract.get_referrer(accounts(3)), Some(accounts(2)));
+        assert_eq!(contract.get_referrer(accounts(2)), None);
+        assert_eq!(contract.get_referral_count(accounts(2)), 1);
+        assert_eq!(contract.get_referral_count(accounts(3)), 0);
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Referrals")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "referral", "security")]
+#[allure_description(
+    "Verifies that unverified referrers are rejected and that a referrer cannot be credited beyond the per-account cap."
+)]
+#[allure_test]
+#[test]
+fn test_referral_rules() {
+    let mut contract = step("Verify the referrer", || {
+        testing_env!(get_context(accounts(1)).build());
+        let mut contract = VersionedContract::new(accounts(1));
+        store(&mut contract, &accounts(2), None);
+        contract
+    });
+
+    step("Unverified referrer is rejected", || {
+        assert_panic_with(
+            || store(&mut contract, &accounts(3), Some(accounts(4))),
+            "ERR_INVALID_REFERRER",
+        );
+    });
+
+    step("Referrer is capped", || {
+        let cap = contract.get_config().max_referrals_per_account;
+        for i in 0..cap {
+            // Fresh context per store keeps gas and log counts within mock VM limits
+            testing_env!(get_context(accounts(1)).build());
+            let user: AccountId = format!("referred{}.near", i).parse().unwrap();
+            store(&mut contract, &user, Some(accounts(2)));
+        }
+        assert_eq!(contract.get_referral_count(accounts(2)), cap);
+        assert_panic_with(
+            || store(&mut contract, &accounts(3), Some(accounts(2))),
+            "ERR_REFERRAL_LIMIT",
+        );
+    });
+}
@@ -0,0 +1,224 @@
+//! Backend wallet rotation tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, get_context, parse_event, BackendRotationCancelledEvent,
+    BackendRotationStartedEvent, BackendWalletUpdatedEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::{accounts, get_logs};
+use near_sdk::testing_env;
+use verified_accounts::{BackendMethod, BackendRotation, VersionedContract};
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Backend Rotation")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "admin", "rotation")]
+#[allure_description(
+    "Verifies that both wallets are authorized during a rotation and the new wallet becomes sole backend on completion."
+)]
+#[allure_test]
+#[test]
+fn test_rotation_accepts_both_wallets_until_complete() {
+    let mut contract = step("Initialize contract and begin rotation", || {
+        testing_env!(get_context(accounts(1)).block_height(100).build());
+        let mut contract = VersionedContract::new(accounts(1));
+        contract.begin_rotation(accounts(2), 50);
+
+        let event: BackendRotationStartedEvent =
+            parse_event(&get_logs(), "backend_rotation_started")
+                .expect("backend_rotation_started event not found");
+        assert_eq!(event.old_wallet, accounts(1));
+        assert_eq!(event.new_wallet, accounts(2));
+        assert_eq!(event.expires_at_block, 150);
+        contract
+    });
+
+    step("Pending rotation is visible", || {
+        assert_eq!(
+            contract.get_backend_rotation(),
+            Some(BackendRotation {
+                new_wallet: accounts(2),
+                expires_at_block: 150,
+            })
+        );
+        assert_eq!(contract.get_backend_wallet(), accounts(1));
+    });
+
+    step("Both wallets can write during the grace period", || {
+        testing_env!(get_context(accounts(1)).block_height(150).build());
+        contract.register_consumer(accounts(3));
+        testing_env!(get_context(accounts(2)).block_height(150).build());
+        contract.register_consumer(accounts(4));
+        assert_eq!(contract.get_consumers().len(), 2);
+    });
+
+    step("New wallet completes rotation", || {
+        testing_env!(get_context(accounts(2)).block_height(151).build());
+        contract.complete_rotation();
+        assert_eq!(contract.get_backend_wallet(), accounts(2));
+        assert_eq!(contract.get_backend_rotation(), None);
+
+        let event: BackendWalletUpdatedEvent = parse_event(&get_logs(), "backend_wallet_updated")
+            .expect("backend_wallet_updated event not found");
+        assert_eq!(event.old_wallet, accounts(1));
+        assert_eq!(event.new_wallet, accounts(2));
+    });
+
+    step("Old wallet is no longer authorized", || {
+        testing_env!(get_context(accounts(1)).block_height(151).build());
+        assert_panic_with(
+            || contract.pause(),
+            "ERR_UNAUTHORIZED: Only backend wallet can pause contract",
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Backend Rotation")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "security", "rotation")]
+#[allure_description(
+    "Verifies that the old wallet expires after the grace period and invalid rotation calls are rejected."
+)]
+#[allure_test]
+#[test]
+fn test_rotation_grace_period_and_guards() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).block_height(10).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Reject completing without a rotation", || {
+        assert_panic_with(
+            || contract.complete_rotation(),
+            "ERR_NO_ROTATION: No backend wallet rotation in progress",
+        );
+    });
+
+    step("Reject rotation from a non-backend account", || {
+        testing_env!(get_context(accounts(2)).block_height(10).build());
+        assert_panic_with(
+            || contract.begin_rotation(accounts(2), 5),
+            "ERR_UNAUTHORIZED: Only backend wallet can begin rotation",
+        );
+    });
+
+    step("Reject a second rotation and direct wallet updates", || {
+        testing_env!(get_context(accounts(1)).block_height(10).build());
+        contract.begin_rotation(accounts(2), 5);
+        assert_panic_with(
+            || contract.begin_rotation(accounts(3), 5),
+            "ERR_ROTATION_IN_PROGRESS",
+        );
+        assert_panic_with(
+            || contract.update_backend_wallet(accounts(3)),
+            "ERR_ROTATION_IN_PROGRESS",
+        );
+    });
+
+    step("Old wallet expires after the grace period", || {
+        testing_env!(get_context(accounts(1)).block_height(16).build());
+        assert_panic_with(
+            || contract.pause(),
+            "ERR_UNAUTHORIZED: Only backend wallet can pause contract",
+        );
+        assert_panic_with(
+            || contract.complete_rotation(),
+            "ERR_UNAUTHORIZED: Only backend wallet can complete rotation",
+        );
+
+        testing_env!(get_context(accounts(2)).block_height(16).build());
+        contract.pause();
+        assert!(contract.is_paused());
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Backend Rotation")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "security", "rotation")]
+#[allure_description(
+    "Verifies that the pending wallet cannot complete the rotation, change configuration or cancel during the grace period, and that the current wallet can cancel it."
+)]
+#[allure_test]
+#[test]
+fn test_cancel_rotation() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).block_height(10).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Reject cancelling without a rotation", || {
+        assert_panic_with(
+            || contract.cancel_rotation(),
+            "ERR_NO_ROTATION: No backend wallet rotation in progress",
+        );
+    });
+
+    step("The new wallet cannot cancel", || {
+        contract.begin_rotation(accounts(2), 5);
+        testing_env!(get_context(accounts(2)).block_height(12).build());
+        assert_panic_with(|| contract.cancel_rotation(), "ERR_UNAUTHORIZED");
+    });
+
+    step(
+        "The pending wallet cannot complete or change configuration",
+        || {
+            testing_env!(get_context(accounts(2)).block_height(12).build());
+            assert_panic_with(
+                || contract.complete_rotation(),
+                "ERR_UNAUTHORIZED: Only backend wallet can complete rotation",
+            );
+            assert_panic_with(
+                || {
+                    contract.grant_permission(
+                        accounts(3),
+                        vec![BackendMethod::StoreVerification],
+                        u64::MAX,
+                    )
+                },
+                "ERR_UNAUTHORIZED",
+            );
+            assert_panic_with(
+                || contract.set_funder(Some(accounts(3))),
+                "ERR_UNAUTHORIZED",
+            );
+            assert!(contract.get_permission(accounts(3)).is_none());
+        },
+    );
+
+    step("The current wallet cancels during the grace period", || {
+        testing_env!(get_context(accounts(1)).block_height(15).build());
+        contract.cancel_rotation();
+        assert_eq!(contract.get_backend_rotation(), None);
+        assert_eq!(contract.get_backend_wallet(), accounts(1));
+
+        let event: BackendRotationCancelledEvent =
+            parse_event(&get_logs(), "backend_rotation_cancelled")
+                .expect("backend_rotation_cancelled event not found");
+        assert_eq!(event.backend_wallet, accounts(1));
+        assert_eq!(event.new_wallet, accounts(2));
+    });
+
+    step("The new wallet is no longer authorized", || {
+        testing_env!(get_context(accounts(2)).block_height(15).build());
+        assert_panic_with(
+            || contract.pause(),
+            "ERR_UNAUTHORIZED: Only backend wallet can pause contract",
+        );
+        assert_panic_with(
+            || contract.complete_rotation(),
+            "ERR_UNAUTHORIZED: Only backend wallet can complete rotation",
+        );
+    });
+
+    step("Cancelling is rejected after the grace period", || {
+        testing_env!(get_context(accounts(1)).block_height(20).build());
+        contract.begin_rotation(accounts(2), 5);
+        testing_env!(get_context(accounts(1)).block_height(26).build());
+        assert_panic_with(|| contract.cancel_rotation(), "ERR_UNAUTHORIZED");
+    });
+}
@@ -37,12 +37,16 @@ fn test_invalid_signature() {
                     challenge: "Identify myself".to_string(),
                     nonce: vec![0; 32].into(),
                     recipient: accounts(0),
+                    app_id: None,
+                    terms_hash: None,
+                    referred_by: None,
                 };
 
                 contract.store_verification(
                     user,
                     sig_data,
                     "test_user_context_data".to_string(),
+                    None,
                 );
             },
             "Invalid NEAR signature - NEP-413 verification failed",
@@ -79,12 +83,16 @@ fn test_invalid_nonce_length() {
                     challenge: "test".to_string(),
                     nonce: vec![0; 16].into(),
                     recipient: accounts(0),
+                    app_id: None,
+                    terms_hash: None,
+                    referred_by: None,
                 };
 
                 contract.store_verification(
                     user,
                     sig_data,
                     "test_user_context_data".to_string(),
+                    None,
                 );
             },
             "Nonce must be exactly 32 bytes",
@@ -121,12 +129,16 @@ fn test_invalid_signature_length() {
                     challenge: "test".to_string(),
                     nonce: vec![0; 32].into(),
                     recipient: accounts(0),
+                    app_id: None,
+                    terms_hash: None,
+                    referred_by: None,
                 };
 
                 contract.store_verification(
                     user,
                     sig_data,
                     "test_user_context_data".to_string(),
+                    None,
                 );
             },
             "Signature must be 64 bytes",
@@ -163,12 +175,16 @@ fn test_nonce_too_long() {
                     challenge: "test".to_string(),
                     nonce: vec![0; 33].into(),
                     recipient: accounts(0),
+                    app_id: None,
+                    terms_hash: None,
+                    referred_by: None,
                 };
 
                 contract.store_verification(
                     user,
                     sig_data,
                     "test_user_context_data".to_string(),
+                    None,
                 );
             },
             "Nonce must be exactly 32 bytes",
@@ -205,12 +221,16 @@ fn test_signature_too_long() {
                     challenge: "test".to_string(),
                     nonce: vec![0; 32].into(),
                     recipient: accounts(0),
+                    app_id: None,
+                    terms_hash: None,
+                    referred_by: None,
                 };
 
                 contract.store_verification(
                     user,
                     sig_data,
                     "test_user_context_data".to_string(),
+                    None,
                 );
             },
             "Signature must be 64 bytes",
@@ -255,7 +275,7 @@ fn test_signature_from_different_key_rejected() {
 
             assert_panic_with(
                 || {
-                    contract.store_verification(user.clone(), sig_data, "ctx".to_string());
+                    contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
                 },
                 "Invalid NEAR signature - NEP-413 verification failed",
             );
@@ -289,7 +309,7 @@ fn test_signature_wrong_nonce_rejected() {
 
         assert_panic_with(
             || {
-                contract.store_verification(user.clone(), sig_data, "ctx".to_string());
+                contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
             },
             "Invalid NEAR signature - NEP-413 verification failed",
         );
@@ -325,7 +345,7 @@ fn test_signature_wrong_recipient_rejected() {
 
         assert_panic_with(
             || {
-                contract.store_verification(user.clone(), sig_data, "ctx".to_string());
+                contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
             },
             "Signature recipient must match contract account",
         );
@@ -360,7 +380,7 @@ fn test_signature_wrong_challenge_rejected() {
 
         assert_panic_with(
             || {
-                contract.store_verification(user.clone(), sig_data, "ctx".to_string());
+                contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
             },
             "Invalid NEAR signature - NEP-413 verification failed",
         );
@@ -393,7 +413,7 @@ fn test_invalid_signature_contents() {
 
         assert_panic_with(
             || {
-                contract.store_verification(user, sig_data, "ctx".to_string());
+                contract.store_verification(user, sig_data, "ctx".to_string(), None);
             },
             "Invalid NEAR signature - NEP-413 verification failed",
         );
@@ -0,0 +1,79 @@
+//! Audit state export tests for verified-accounts contract
+
+use super::helpers::{create_signer, create_valid_signature, get_context};
+use allure_rs::prelude::*;
+use near_sdk::test_utils::accounts;
+use near_sdk::testing_env;
+use near_sdk::{env, AccountId};
+use verified_accounts::{StateChunkKind, VersionedContract, VersionedVerification};
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("State Export")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "query", "audit")]
+#[allure_description(
+    "Verifies that exported chunks decode to the stored state and that the state root combines the chunk hashes."
+)]
+#[allure_test]
+#[test]
+fn test_export_state_chunks_and_root() {
+    let mut contract = step("Initialize contract", || {
+        let backend = accounts(1);
+        testing_env!(get_context(backend.clone()).build());
+        VersionedContract::new(backend)
+    });
+
+    step("Empty state has no chunks", || {
+        let root = contract.get_state_root();
+        assert_eq!(root.chunk_count, 0);
+        assert_eq!(<[u8; 32]>::from(root.root), env::sha256_array([]));
+    });
+
+    step("Store two verifications and register a consumer", || {
+        for (i, user) in [accounts(2), accounts(3)].iter().enumerate() {
+            let signer = create_signer(user);
+            let sig_data = create_valid_signature(
+                &signer,
+                user,
+                "Identify myself",
+                &[i as u8 + 1; 32],
+                &accounts(0),
+            );
+            contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
+        }
+        contract.register_consumer(accounts(4));
+    });
+
+    let (accounts_chunk, consumers_chunk) = step("Export and decode chunks", || {
+        let accounts_chunk = contract.export_state_chunk(StateChunkKind::Accounts, 0, 100);
+        assert_eq!(accounts_chunk.count, 2);
+        let decoded: Vec<(AccountId, VersionedVerification)> =
+            near_sdk::borsh::from_slice(&accounts_chunk.data.0).unwrap();
+        let ids: Vec<AccountId> = decoded.iter().map(|(id, _)| id.clone()).collect();
+        assert_eq!(ids, vec![accounts(2), accounts(3)]);
+        assert_eq!(
+            <[u8; 32]>::from(accounts_chunk.hash),
+            env::sha256_array(&accounts_chunk.data.0)
+        );
+
+        let consumers_chunk = contract.export_state_chunk(StateChunkKind::Consumers, 0, 100);
+        let decoded: Vec<AccountId> = near_sdk::borsh::from_slice(&consumers_chunk.data.0).unwrap();
+        assert_eq!(decoded, vec![accounts(4)]);
+
+        let tail = contract.export_state_chunk(StateChunkKind::Accounts, 1, 100);
+        assert_eq!(tail.count, 1);
+        (accounts_chunk, consumers_chunk)
+    });
+
+    step("State root combines chunk hashes in kind order", || {
+        let root = contract.get_state_root();
+        assert_eq!(root.chunk_size, 100);
+        assert_eq!(root.chunk_count, 2);
+
+        let mut hashes = Vec::new();
+        hashes.extend_from_slice(&<[u8; 32]>::from(accounts_chunk.hash));
+        hashes.extend_from_slice(&<[u8; 32]>::from(consumers_chunk.hash));
+        assert_eq!(<[u8; 32]>::from(root.root), env::sha256_array(&hashes));
+    });
+}
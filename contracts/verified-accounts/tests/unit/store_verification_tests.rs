@@ -58,6 +58,7 @@ fn test_happy_path_store_verification() {
             user.clone(),
             sig_data,
             "test_user_context_data".to_string(),
+            None,
         );
     });
 
@@ -109,7 +110,7 @@ fn test_verification_timestamp_matches_block_time() {
         });
 
     step("Store verification", || {
-        contract.store_verification(user.clone(), sig_data, "ctx".to_string());
+        contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
     });
 
     step("Verify timestamp matches block time", || {
@@ -142,7 +143,7 @@ fn test_double_verification_rejected() {
     step("Store first verification for user", || {
         let sig_one =
             create_valid_signature(&signer, &user, "Identify myself", &[3; 32], &accounts(0));
-        contract.store_verification(user.clone(), sig_one, "ctx".to_string());
+        contract.store_verification(user.clone(), sig_one, "ctx".to_string(), None);
     });
 
     step("Attempt second verification for same user", || {
@@ -150,9 +151,62 @@ fn test_double_verification_rejected() {
             create_valid_signature(&signer, &user, "Identify myself", &[4; 32], &accounts(0));
         assert_panic_with(
             || {
-                contract.store_verification(user.clone(), sig_two, "ctx".to_string());
+                contract.store_verification(user.clone(), sig_two, "ctx".to_string(), None);
             },
             "NEAR account already verified",
         );
     });
 }
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Store Verification")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "events", "tracing")]
+#[allure_description(
+    "Verifies that verification_stored carries the signature's correlation ID, and that each verification gets its own."
+)]
+#[allure_test]
+#[test]
+fn test_verification_correlation_id() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    let first_id = step("Store a verification", || {
+        let user = accounts(2);
+        let sig_data = create_valid_signature(
+            &create_signer(&user),
+            &user,
+            "Identify myself",
+            &[5; 32],
+            &accounts(0),
+        );
+        let expected = sig_data.correlation_id();
+        contract.store_verification(user, sig_data, "ctx".to_string(), None);
+
+        let logs = get_logs();
+        let event: VerificationStoredEvent =
+            parse_event(&logs, "verification_stored").expect("verification_stored event not found");
+        assert_eq!(event.correlation_id, expected);
+        assert_eq!(event.correlation_id.len(), 64);
+        expected
+    });
+
+    step("Another verification gets a different ID", || {
+        testing_env!(get_context(accounts(1)).build());
+        let user = accounts(3);
+        let sig_data = create_valid_signature(
+            &create_signer(&user),
+            &user,
+            "Identify myself",
+            &[6; 32],
+            &accounts(0),
+        );
+        contract.store_verification(user, sig_data, "ctx".to_string(), None);
+        let event: VerificationStoredEvent = parse_event(&get_logs(), "verification_stored")
+            .expect("verification_stored event not found");
+        assert_ne!(event.correlation_id, first_id);
+    });
+}
@@ -35,7 +35,7 @@ fn test_stress_max_length_inputs() {
         // Maximum user context data is 4096 characters
         let max_user_context = "c".repeat(4096);
 
-        contract.store_verification(user.clone(), sig_data, max_user_context);
+        contract.store_verification(user.clone(), sig_data, max_user_context, None);
     });
 
     step("Verify account is stored correctly", || {
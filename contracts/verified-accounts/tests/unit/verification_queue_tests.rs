@@ -0,0 +1,226 @@
+//! Verification request queue tests for verified-accounts contract
+
+use super::helpers::{
+    assert_panic_with, create_signer, create_valid_signature, get_context, parse_event,
+    VerificationRequestedEvent, VerificationRequestsRemovedEvent,
+};
+use allure_rs::prelude::*;
+use near_sdk::mock::MockAction;
+use near_sdk::test_utils::{accounts, get_created_receipts, get_logs, VMContextBuilder};
+use near_sdk::{testing_env, AccountId, NearToken};
+use verified_accounts::{QueuedRequest, VersionedContract};
+
+/// Deposit `request_verification` requires
+const REQUEST_DEPOSIT: NearToken = NearToken::from_millinear(10);
+
+/// Context for `user` calling `request_verification` with the deposit attached
+fn request_context(user: AccountId) -> VMContextBuilder {
+    let mut builder = get_context(user);
+    builder.attached_deposit(REQUEST_DEPOSIT);
+    builder
+}
+
+/// Transfers created in the current context as `(receiver, amount)`
+fn created_refunds() -> Vec<(AccountId, NearToken)> {
+    get_created_receipts()
+        .into_iter()
+        .flat_map(|receipt| {
+            let receiver = receipt.receiver_id.clone();
+            receipt
+                .actions
+                .into_iter()
+                .filter_map(move |action| match action {
+                    MockAction::Transfer { deposit, .. } => Some((receiver.clone(), deposit)),
+                    _ => None,
+                })
+        })
+        .collect()
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Verification Queue")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "queue")]
+#[allure_description(
+    "Verifies that users can queue one request each, positions follow request order, and storing a verification clears the entry."
+)]
+#[allure_test]
+#[test]
+fn test_request_verification_queue() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("Two users queue requests", || {
+        testing_env!(request_context(accounts(2)).block_timestamp(100).build());
+        contract.request_verification();
+        let event: VerificationRequestedEvent = parse_event(&get_logs(), "verification_requested")
+            .expect("verification_requested event not found");
+        assert_eq!(event.account_id, accounts(2));
+        assert_eq!(event.requested_at, 100);
+
+        testing_env!(request_context(accounts(3)).block_timestamp(200).build());
+        contract.request_verification();
+
+        assert_eq!(contract.get_verification_queue_depth(), 2);
+        assert_eq!(
+            contract.get_verification_request(accounts(2)),
+            Some(QueuedRequest {
+                position: 0,
+                requested_at: 100,
+            })
+        );
+        assert_eq!(
+            contract
+                .get_verification_request(accounts(3))
+                .map(|r| r.position),
+            Some(1)
+        );
+        assert_eq!(contract.get_verification_request(accounts(4)), None);
+    });
+
+    step("Duplicate requests are rejected", || {
+        assert_panic_with(
+            || contract.request_verification(),
+            "ERR_ALREADY_REQUESTED: Verification already requested",
+        );
+    });
+
+    step("Storing the verification clears the queue entry", || {
+        testing_env!(get_context(accounts(1)).block_timestamp(300).build());
+        let user = accounts(2);
+        let signer = create_signer(&user);
+        let sig_data =
+            create_valid_signature(&signer, &user, "Identify myself", &[1; 32], &accounts(0));
+        contract.store_verification(user.clone(), sig_data, "ctx".to_string(), None);
+
+        assert_eq!(created_refunds(), vec![(user.clone(), REQUEST_DEPOSIT)]);
+        assert_eq!(contract.get_verification_request(user.clone()), None);
+        assert_eq!(contract.get_verification_queue_depth(), 1);
+        assert_eq!(
+            contract
+                .get_verification_request(accounts(3))
+                .map(|r| r.position),
+            Some(0)
+        );
+
+        testing_env!(request_context(user).build());
+        assert_panic_with(
+            || contract.request_verification(),
+            "ERR_ALREADY_VERIFIED: NEAR account already verified",
+        );
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Verification Queue")]
+#[allure_severity("normal")]
+#[allure_tags("unit", "queue", "authorization")]
+#[allure_description(
+    "Verifies that only the backend can consume or expire queue entries, and that expiry only removes stale requests."
+)]
+#[allure_test]
+#[test]
+fn test_consume_and_expire_requests() {
+    let mut contract = step("Queue three requests", || {
+        testing_env!(get_context(accounts(1)).build());
+        let mut contract = VersionedContract::new(accounts(1));
+        for (i, user) in [accounts(2), accounts(3), accounts(4)]
+            .into_iter()
+            .enumerate()
+        {
+            testing_env!(request_context(user)
+                .block_timestamp(100 * (i as u64 + 1))
+                .build());
+            contract.request_verification();
+        }
+        contract
+    });
+
+    step("Users cannot consume or expire requests", || {
+        testing_env!(get_context(accounts(2)).build());
+        assert_panic_with(
+            || contract.consume_verification_request(accounts(3)),
+            "ERR_UNAUTHORIZED: Only backend wallet can consume verification requests",
+        );
+        assert_panic_with(
+            || {
+                contract.expire_verification_requests(u64::MAX, 10);
+            },
+            "ERR_UNAUTHORIZED: Only backend wallet can expire verification requests",
+        );
+    });
+
+    step("Backend consumes one request", || {
+        testing_env!(get_context(accounts(1)).build());
+        contract.consume_verification_request(accounts(3));
+        let event: VerificationRequestsRemovedEvent =
+            parse_event(&get_logs(), "verification_requests_removed")
+                .expect("verification_requests_removed event not found");
+        assert_eq!(event.account_ids, vec![accounts(3)]);
+        assert_eq!(event.reason, "consumed");
+        assert_eq!(created_refunds(), vec![(accounts(3), REQUEST_DEPOSIT)]);
+
+        assert_panic_with(
+            || contract.consume_verification_request(accounts(3)),
+            "ERR_REQUEST_NOT_FOUND",
+        );
+    });
+
+    step("Backend expires requests older than the cutoff", || {
+        testing_env!(get_context(accounts(1)).build());
+        assert_eq!(contract.expire_verification_requests(300, 10), 1);
+        let event: VerificationRequestsRemovedEvent =
+            parse_event(&get_logs(), "verification_requests_removed")
+                .expect("verification_requests_removed event not found");
+        assert_eq!(event.account_ids, vec![accounts(2)]);
+        assert_eq!(event.reason, "expired");
+        assert_eq!(created_refunds(), vec![(accounts(2), REQUEST_DEPOSIT)]);
+
+        assert_eq!(contract.expire_verification_requests(300, 10), 0);
+        assert_eq!(contract.get_verification_queue_depth(), 1);
+        assert!(contract.get_verification_request(accounts(4)).is_some());
+    });
+}
+
+#[allure_parent_suite("Near Citizens House")]
+#[allure_suite_label("Verified Accounts Unit Tests")]
+#[allure_sub_suite("Verification Queue")]
+#[allure_severity("critical")]
+#[allure_tags("unit", "queue", "security")]
+#[allure_description(
+    "Verifies that a request needs the storage deposit and that one account holds at most one queue slot, so it cannot fill the queue."
+)]
+#[allure_test]
+#[test]
+fn test_one_account_cannot_fill_queue() {
+    let mut contract = step("Initialize contract", || {
+        testing_env!(get_context(accounts(1)).build());
+        VersionedContract::new(accounts(1))
+    });
+
+    step("A request without the deposit is rejected", || {
+        testing_env!(get_context(accounts(2)).build());
+        assert_panic_with(
+            || contract.request_verification(),
+            "ERR_REQUEST_DEPOSIT_REQUIRED",
+        );
+        assert_eq!(contract.get_verification_queue_depth(), 0);
+        assert_eq!(
+            contract.get_config().verification_request_deposit.0,
+            REQUEST_DEPOSIT.as_yoctonear()
+        );
+    });
+
+    step("Repeated requests from one account take one slot", || {
+        testing_env!(request_context(accounts(2)).build());
+        contract.request_verification();
+        for _ in 0..5 {
+            assert_panic_with(|| contract.request_verification(), "ERR_ALREADY_REQUESTED");
+        }
+        assert_eq!(contract.get_verification_queue_depth(), 1);
+    });
+}